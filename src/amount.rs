@@ -0,0 +1,198 @@
+//! Amounts of materials: a material paired with its mass, converted to volume through
+//! its `Density`, split into parts and mixed into compositions.
+//!
+//! Volumes use the average density of the material, mixtures assume that the volumes
+//! of the components add up.
+//!
+//! Количество материала: масса, объём, деление и смешивание.
+
+use crate::{Alloy, CubicMeter, Density, KGm3, Kilogram, Material, Percent};
+use std::any::TypeId;
+use std::ops::Add;
+
+/// Part of a composition: the type of the material identifies it
+pub type Part = (TypeId, &'static dyn Material, Kilogram);
+
+/// Mass of the parts that are the element with `symbol`
+fn get_element_mass(parts: &[Part], symbol: &str) -> Kilogram {
+    parts
+        .iter()
+        .filter(|(_, material, _)| material.as_element().is_some_and(|element| element.get_symbol() == symbol))
+        .map(|(_, _, mass)| mass)
+        .sum()
+}
+
+/// Mass of a material, e.g. 3 kg of `Copper`
+pub struct Amount<M: 'static> {
+    material: &'static M,
+    mass: Kilogram,
+}
+
+// derive would require `M: Clone`, materials are not
+impl<M> Clone for Amount<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<M> Copy for Amount<M> {}
+
+impl<M: Material + Density + 'static> Amount<M> {
+    pub fn from_mass(material: &'static M, mass: Kilogram) -> Self {
+        Amount {material, mass}
+    }
+    pub fn from_volume(material: &'static M, volume: CubicMeter) -> Self {
+        Amount {material, mass: material.get_density_avg_kgm3() * volume}
+    }
+    pub fn get_material(&self) -> &'static M {
+        self.material
+    }
+    pub fn get_mass_kg(&self) -> Kilogram {
+        self.mass
+    }
+    pub fn get_volume_m3(&self) -> CubicMeter {
+        self.mass / self.material.get_density_avg_kgm3()
+    }
+    /// Splits off `share` (0.0..1.0) of the mass, returns the part split off and the rest
+    pub fn split(self, share: f32) -> (Self, Self) {
+        let part = self.mass * share.clamp(0.0, 1.0);
+        (Amount {mass: part, ..self}, Amount {mass: self.mass - part, ..self})
+    }
+    /// Composition of this amount alone
+    pub fn to_composition(&self) -> Composition {
+        let mut composition = Composition::default();
+        composition.add(self.material, self.mass);
+        composition
+    }
+    /// Mixes with an amount of another material
+    pub fn mix<N: Material + Density + 'static>(&self, other: &Amount<N>) -> Composition {
+        self.to_composition().mix(&other.to_composition())
+    }
+}
+
+impl<M> Add for Amount<M> {
+    type Output = Amount<M>;
+    fn add(self, other: Self) -> Self {
+        Amount {material: self.material, mass: self.mass + other.mass}
+    }
+}
+
+#[derive(Clone, Default)]
+/// Mixture of materials by mass, every material with `Density`
+pub struct Composition {
+    pub parts: Vec<Part>,
+}
+
+impl Composition {
+    /// Adds `mass` of `material`, merging it with the same material already there
+    pub fn add<M: Material + Density + 'static>(&mut self, material: &'static M, mass: Kilogram) {
+        self.add_part((TypeId::of::<M>(), material, mass));
+    }
+    fn add_part(&mut self, (id, material, mass): Part) {
+        match self.parts.iter_mut().find(|(part, _, _)| *part == id) {
+            Some((_, _, part_mass)) => *part_mass += mass,
+            None => self.parts.push((id, material, mass)),
+        }
+    }
+    /// Composition of both mixed together
+    pub fn mix(&self, other: &Composition) -> Composition {
+        let mut mixed = self.clone();
+        for part in &other.parts {
+            mixed.add_part(*part);
+        }
+        mixed
+    }
+    pub fn get_mass_kg(&self) -> Kilogram {
+        self.parts.iter().map(|(_, _, mass)| mass).sum()
+    }
+    /// Volume with the volumes of the components added up, m³
+    pub fn get_volume_m3(&self) -> CubicMeter {
+        self.parts
+            .iter()
+            .filter_map(|(_, material, mass)| Some(mass / material.as_density()?.get_density_avg_kgm3()))
+            .sum()
+    }
+    /// Density, kg/m³. 0 for an empty composition
+    pub fn get_density_kgm3(&self) -> KGm3 {
+        let volume = self.get_volume_m3();
+        if volume > 0.0 {self.get_mass_kg() / volume} else {0.0}
+    }
+    /// Mass share of `material`, %. 0 for an empty composition
+    pub fn get_mass_pct<M: Material + 'static>(&self, _material: &M) -> Percent {
+        let total = self.get_mass_kg();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let mass: Kilogram = self.parts.iter().filter(|(id, _, _)| *id == TypeId::of::<M>()).map(|(_, _, mass)| mass).sum();
+        mass / total * 100.0
+    }
+    /// Every listed element of the alloy `A` is within its `CHEMICAL_COMPOSITION` range
+    /// (0% when absent) and there is nothing else. `false` for an empty composition
+    pub fn is_alloy<A: Alloy>(&self) -> bool {
+        let total = self.get_mass_kg();
+        if total <= 0.0 || !total.is_finite() {
+            return false;
+        }
+        let listed = |symbol: &str| A::CHEMICAL_COMPOSITION.iter().any(|(element, _, _)| element.get_symbol() == symbol);
+        let only_listed = self
+            .parts
+            .iter()
+            .all(|(_, material, _)| material.as_element().is_some_and(|element| listed(element.get_symbol())));
+        only_listed
+            && A::CHEMICAL_COMPOSITION.iter().all(|(element, min, max)| {
+                let percent = get_element_mass(&self.parts, element.get_symbol()) / total * 100.0;
+                (*min..=*max).contains(&percent)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aluminium, Brass, Copper, Dural, Granite, Magnesium, Manganese, Zinc};
+
+    #[test]
+    fn amount() {
+        let copper = Amount::from_mass(&Copper, 3.0);
+        assert!((copper.get_volume_m3() - 3.0 / 8920.0).abs() < 1e-9);
+        let same = Amount::from_volume(&Copper, copper.get_volume_m3());
+        assert!((same.get_mass_kg() - 3.0).abs() < 1e-5);
+        let (part, rest) = copper.split(0.25);
+        assert_eq!((part.get_mass_kg(), rest.get_mass_kg()), (0.75, 2.25));
+        assert_eq!((part + rest).get_mass_kg(), 3.0);
+    }
+    #[test]
+    fn mixing() {
+        let brass = Amount::from_mass(&Copper, 7.0).mix(&Amount::from_mass(&Zinc, 3.0));
+        assert_eq!(brass.get_mass_kg(), 10.0);
+        assert!((brass.get_mass_pct(&Zinc) - 30.0).abs() < 1e-4);
+        assert!(brass.is_alloy::<Brass>());
+        assert!(!brass.is_alloy::<Dural>());
+        // 60 cm³ of copper and 40 cm³ of zinc make brass too
+        assert!(Amount::from_volume(&Copper, 60.0e-6).mix(&Amount::from_volume(&Zinc, 40.0e-6)).is_alloy::<Brass>());
+        // too little zinc for brass
+        let bronze = Amount::from_mass(&Copper, 9.5).mix(&Amount::from_mass(&Zinc, 0.5));
+        assert!(!bronze.is_alloy::<Brass>());
+        // the same material is merged, rock is not an element of any alloy
+        let more = brass.mix(&Amount::from_mass(&Copper, 1.0).to_composition());
+        assert_eq!(more.parts.len(), 2);
+        assert!(!more.mix(&Amount::from_mass(&Granite, 0.1).to_composition()).is_alloy::<Brass>());
+        // every listed element must be there, dural without manganese is not dural
+        let mut no_manganese = Composition::default();
+        no_manganese.add(&Aluminium, 94.0);
+        no_manganese.add(&Copper, 4.5);
+        no_manganese.add(&Magnesium, 1.5);
+        assert!(!no_manganese.is_alloy::<Dural>());
+        let mut dural = no_manganese.clone();
+        dural.add(&Manganese, 0.5);
+        assert!(dural.is_alloy::<Dural>());
+        // nothing is not an alloy
+        assert!(!Composition::default().is_alloy::<Brass>());
+        assert!(!Amount::from_mass(&Copper, 0.0).mix(&Amount::from_mass(&Zinc, 0.0)).is_alloy::<Brass>());
+        assert!(!Amount::from_mass(&Copper, 1.0).to_composition().is_alloy::<Brass>());
+        // nothing weighs nothing
+        assert_eq!(Composition::default().get_mass_pct(&Zinc), 0.0);
+        assert_eq!(Composition::default().get_density_kgm3(), 0.0);
+        let density = brass.get_density_kgm3();
+        assert!((Zinc.get_density_avg_kgm3()..Copper.get_density_avg_kgm3()).contains(&density));
+    }
+}
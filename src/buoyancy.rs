@@ -0,0 +1,104 @@
+//! Archimedes' principle: whether a body floats in a fluid, how deep it sits and
+//! the forces on it.
+//!
+//! The body and the fluid are any materials with `Density`: water, sea water, molten
+//! metals, or air and other gases for balloons. Densities are taken at the conditions
+//! they are tabulated for (see `Density::get_density_conditions`), use the `_kgm3`
+//! variants for densities at other temperatures, e.g. hot air or hollow bodies.
+//!
+//! Закон Архимеда: плавучесть, осадка и выталкивающая сила.
+
+use crate::{CubicMeter, Density, KGm3, Kilogram, Newton};
+
+/// Standard gravity, m/s²
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Forces on a body in a fluid
+pub struct Buoyancy {
+    /// Body is lighter than the fluid
+    pub floats: bool,
+    /// Share of the volume under the surface when floating freely, 1.0 - fully submerged
+    pub submerged_fraction: f32,
+    /// Weight of the body, N
+    pub weight: Newton,
+    /// Buoyant force on the fully submerged body, N
+    pub buoyant_force: Newton,
+    /// Weight minus buoyant force of the fully submerged body, N. <br>
+    /// Negative - the body rises, e.g. a balloon lifts this much.
+    pub net_weight: Newton,
+}
+
+/// Forces on a body of `volume` and density `body`, kg/m³, in a fluid of density `fluid`, kg/m³
+pub fn get_buoyancy_kgm3(body: KGm3, volume: CubicMeter, fluid: KGm3) -> Buoyancy {
+    let weight = body * volume * STANDARD_GRAVITY;
+    let buoyant_force = fluid * volume * STANDARD_GRAVITY;
+    Buoyancy {
+        floats: body < fluid,
+        submerged_fraction: if fluid > 0.0 {(body / fluid).min(1.0)} else {1.0},
+        weight,
+        buoyant_force,
+        net_weight: weight - buoyant_force,
+    }
+}
+
+/// Forces on a body of `volume` made of `body` material in `fluid`
+pub fn get_buoyancy(body: &dyn Density, volume: CubicMeter, fluid: &dyn Density) -> Buoyancy {
+    get_buoyancy_kgm3(body.get_density_avg_kgm3(), volume, fluid.get_density_avg_kgm3())
+}
+
+/// `true` if a solid body of `body` material floats in `fluid`
+pub fn is_floating(body: &dyn Density, fluid: &dyn Density) -> bool {
+    body.get_density_avg_kgm3() < fluid.get_density_avg_kgm3()
+}
+
+/// Share of the volume of a floating body under the surface, 1.0 - it sinks
+pub fn get_submerged_fraction(body: &dyn Density, fluid: &dyn Density) -> f32 {
+    get_buoyancy(body, 1.0, fluid).submerged_fraction
+}
+
+/// Mass a balloon of `volume` filled with gas of density `gas` can carry in `air`, kg. <br>
+/// Both densities in kg/m³, the envelope is part of the payload. Negative - it does not lift.
+pub fn get_payload_kg(gas: KGm3, volume: CubicMeter, air: KGm3) -> Kilogram {
+    -get_buoyancy_kgm3(gas, volume, air).net_weight / STANDARD_GRAVITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Air, Gas, Gold, Granite, Helium, Ice, Iron, Mercury, MoltenIron, Oak, SeaWater, Water};
+
+    #[test]
+    fn float_or_sink() {
+        // about 90% of an iceberg is under sea water
+        let iceberg = get_submerged_fraction(&Ice, &SeaWater);
+        assert!((0.87..0.91).contains(&iceberg), "{}", iceberg);
+        assert!(get_submerged_fraction(&Ice, &Water) > iceberg);
+        assert_eq!(get_submerged_fraction(&Granite, &Water), 1.0);
+        // granite floats on molten iron
+        assert!(is_floating(&Granite, &MoltenIron));
+        // iron floats on mercury, gold sinks in it
+        assert!(is_floating(&Iron, &Mercury));
+        assert!(!is_floating(&Gold, &Mercury));
+        let oak = get_buoyancy(&Oak, 0.01, &Water);
+        assert!(oak.floats && oak.net_weight < 0.0);
+        assert!((oak.buoyant_force - 97.89).abs() < 0.1, "{}N", oak.buoyant_force);
+        let granite = get_buoyancy(&Granite, 0.01, &Water);
+        assert!((granite.weight - granite.buoyant_force - granite.net_weight).abs() < 1e-3);
+        assert!(granite.net_weight > 0.0);
+    }
+    #[test]
+    fn balloon() {
+        // 1 m³ of helium lifts ~1.1 kg at normal conditions
+        let helium = get_payload_kg(Helium.get_density_avg_kgm3(), 1.0, Air.get_density_avg_kgm3());
+        assert!((1.05..1.15).contains(&helium), "{}kg", helium);
+        // hot air at 100 °C in 2800 m³ of air at 20 °C lifts ~700 kg
+        let hot = Air.get_density_kgm3_at_tp(373.15, 101.325);
+        let cold = Air.get_density_kgm3_at_tp(293.15, 101.325);
+        let payload = get_payload_kg(hot, 2800.0, cold);
+        assert!((650.0..800.0).contains(&payload), "{}kg", payload);
+        assert!(get_payload_kg(cold, 1.0, hot) < 0.0);
+        assert!(is_floating(&Helium, &Air));
+        assert!(get_buoyancy(&Helium, 1.0, &Air).net_weight < 0.0);
+    }
+}
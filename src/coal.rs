@@ -0,0 +1,142 @@
+//! Coal rank classification by ASTM D388 from proximate analysis and calorific value.
+//!
+//! Классификация углей по стадии углефикации (ASTM D388).
+
+use crate::{Material, MJ_kg, Percent, BTU_LB_IN_MJ_KG};
+use crate::{Anthracite, BituminousCoal, BrownCoal, SubBituminousCoal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Proximate analysis of a coal as received, mass %. <br>
+/// `moisture`, `volatile_matter`, `fixed_carbon` and `ash` add up to 100.
+pub struct ProximateAnalysis {
+    pub moisture: Percent,
+    pub volatile_matter: Percent,
+    pub fixed_carbon: Percent,
+    pub ash: Percent,
+    /// Total sulfur, used by the Parr mineral-matter-free formulas
+    pub sulfur: Percent,
+    /// Higher (gross) calorific value as received, MJ/kg
+    pub hhv: MJ_kg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Coal class, ASTM D388
+pub enum CoalClass {
+    /// Антрацитовые
+    Anthracitic,
+    /// Каменные (битуминозные)
+    Bituminous,
+    /// Суббитуминозные
+    SubBituminous,
+    /// Бурые (лигниты)
+    Lignitic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Coal rank (group), ASTM D388
+pub enum CoalRank {
+    MetaAnthracite,
+    Anthracite,
+    SemiAnthracite,
+    LowVolatileBituminous,
+    MediumVolatileBituminous,
+    HighVolatileABituminous,
+    HighVolatileBBituminous,
+    HighVolatileCBituminous,
+    SubBituminousA,
+    SubBituminousB,
+    SubBituminousC,
+    LigniteA,
+    LigniteB,
+}
+
+impl CoalRank {
+    pub fn get_class(&self) -> CoalClass {
+        match self {
+            CoalRank::MetaAnthracite | CoalRank::Anthracite | CoalRank::SemiAnthracite => CoalClass::Anthracitic,
+            CoalRank::LowVolatileBituminous
+            | CoalRank::MediumVolatileBituminous
+            | CoalRank::HighVolatileABituminous
+            | CoalRank::HighVolatileBBituminous
+            | CoalRank::HighVolatileCBituminous => CoalClass::Bituminous,
+            CoalRank::SubBituminousA | CoalRank::SubBituminousB | CoalRank::SubBituminousC => CoalClass::SubBituminous,
+            CoalRank::LigniteA | CoalRank::LigniteB => CoalClass::Lignitic,
+        }
+    }
+    /// Material of the crate describing this rank
+    pub fn get_material(&self) -> &'static dyn Material {
+        match self.get_class() {
+            CoalClass::Anthracitic => &Anthracite,
+            CoalClass::Bituminous => &BituminousCoal,
+            CoalClass::SubBituminous => &SubBituminousCoal,
+            CoalClass::Lignitic => &BrownCoal,
+        }
+    }
+}
+
+impl ProximateAnalysis {
+    /// Fixed carbon on dry, mineral-matter-free basis (Parr formula), %
+    pub fn get_fixed_carbon_dmmf(&self) -> Percent {
+        100.0 * (self.fixed_carbon - 0.15 * self.sulfur)
+            / (100.0 - (self.moisture + 1.08 * self.ash + 0.55 * self.sulfur))
+    }
+    /// Volatile matter on dry, mineral-matter-free basis, %
+    pub fn get_volatile_matter_dmmf(&self) -> Percent {
+        100.0 - self.get_fixed_carbon_dmmf()
+    }
+    /// Gross calorific value on moist, mineral-matter-free basis (Parr formula), BTU/lb
+    pub fn get_hhv_mmmf_btu_lb(&self) -> f32 {
+        let btu = self.hhv * BTU_LB_IN_MJ_KG;
+        100.0 * (btu - 50.0 * self.sulfur) / (100.0 - (1.08 * self.ash + 0.55 * self.sulfur))
+    }
+    /// Rank by ASTM D388. `agglomerating` separates high volatile C bituminous
+    /// from sub-bituminous A coals between 10 500 and 11 500 BTU/lb.
+    pub fn get_coal_rank(&self, agglomerating: bool) -> CoalRank {
+        let fixed_carbon = self.get_fixed_carbon_dmmf();
+        if fixed_carbon >= 69.0 {
+            return match fixed_carbon {
+                fc if fc >= 98.0 => CoalRank::MetaAnthracite,
+                fc if fc >= 92.0 => CoalRank::Anthracite,
+                fc if fc >= 86.0 => CoalRank::SemiAnthracite,
+                fc if fc >= 78.0 => CoalRank::LowVolatileBituminous,
+                _ => CoalRank::MediumVolatileBituminous,
+            };
+        }
+        match self.get_hhv_mmmf_btu_lb() {
+            btu if btu >= 14_000.0 => CoalRank::HighVolatileABituminous,
+            btu if btu >= 13_000.0 => CoalRank::HighVolatileBBituminous,
+            btu if btu >= 11_500.0 => CoalRank::HighVolatileCBituminous,
+            btu if btu >= 10_500.0 && agglomerating => CoalRank::HighVolatileCBituminous,
+            btu if btu >= 10_500.0 => CoalRank::SubBituminousA,
+            btu if btu >= 9_500.0 => CoalRank::SubBituminousB,
+            btu if btu >= 8_300.0 => CoalRank::SubBituminousC,
+            btu if btu >= 6_300.0 => CoalRank::LigniteA,
+            _ => CoalRank::LigniteB,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CoalAnalysis, SLang};
+
+    #[test]
+    fn coal_rank() {
+        assert_eq!(BrownCoal.get_coal_rank(), CoalRank::LigniteA);
+        assert_eq!(SubBituminousCoal.get_coal_rank(), CoalRank::SubBituminousB);
+        assert_eq!(BituminousCoal.get_coal_rank(), CoalRank::HighVolatileBBituminous);
+        assert_eq!(Anthracite.get_coal_rank(), CoalRank::Anthracite);
+        assert_eq!(BrownCoal.get_coal_rank().get_material().get_name(SLang::EN), "Brown Coal");
+        let boundary = ProximateAnalysis {
+            moisture: 10.0,
+            volatile_matter: 40.0,
+            fixed_carbon: 45.0,
+            ash: 5.0,
+            sulfur: 0.0,
+            hhv: 11_000.0 * (100.0 - 5.4) / 100.0 / BTU_LB_IN_MJ_KG,
+        };
+        assert_eq!(boundary.get_coal_rank(true), CoalRank::HighVolatileCBituminous);
+        assert_eq!(boundary.get_coal_rank(false), CoalRank::SubBituminousA);
+    }
+}
@@ -0,0 +1,221 @@
+//! Combustion of a fuel with a known elemental (ultimate) composition in air:
+//! stoichiometric air, flue gas, CO₂ emission and adiabatic flame temperature.
+//!
+//! Сгорание топлива известного элементного состава в воздухе.
+
+use crate::{Celsius, Kelvin, MJ_kg, Percent};
+
+/// Molar volume of an ideal gas at 0 °C and 101.325 kPa, m³/kmol
+pub const MOLAR_VOLUME_NM3: f32 = 22.414;
+/// Mass fraction of oxygen in dry air
+pub const AIR_OXYGEN_MASS_FRACTION: f32 = 0.2314;
+/// Mole fraction of oxygen in dry air
+pub const AIR_OXYGEN_MOLE_FRACTION: f32 = 0.2095;
+/// Highest moisture plus ash of a fuel, mass %, some combustible matter is always left
+pub const MAX_MOISTURE_ASH_PCT: Percent = 99.0;
+
+const M_C: f32 = 12.011;
+const M_H2: f32 = 2.016;
+const M_O2: f32 = 31.998;
+const M_N2: f32 = 28.013;
+const M_S: f32 = 32.06;
+const M_H2O: f32 = 18.015;
+const M_CO2: f32 = 44.009;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Elemental (ultimate) analysis of a fuel, mass %. <br>
+/// All fields should add up to 100 on the basis the analysis is given for.
+pub struct FuelComposition {
+    pub carbon: Percent,
+    pub hydrogen: Percent,
+    pub oxygen: Percent,
+    pub nitrogen: Percent,
+    pub sulfur: Percent,
+    pub moisture: Percent,
+    pub ash: Percent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Wet flue gas, kmol per kg of fuel (or mole fractions, see `get_mole_fractions`). <br>
+/// Argon of the air is counted as nitrogen.
+pub struct FlueGas {
+    pub co2: f32,
+    pub h2o: f32,
+    pub so2: f32,
+    pub n2: f32,
+    pub o2: f32,
+}
+
+impl FlueGas {
+    pub fn get_total(&self) -> f32 {
+        self.co2 + self.h2o + self.so2 + self.n2 + self.o2
+    }
+    /// Wet flue gas volume at 0 °C and 101.325 kPa, Nm³ per kg of fuel
+    pub fn get_volume_nm3(&self) -> f32 {
+        self.get_total() * MOLAR_VOLUME_NM3
+    }
+    /// Dry flue gas volume (water removed), Nm³ per kg of fuel
+    pub fn get_dry_volume_nm3(&self) -> f32 {
+        (self.get_total() - self.h2o) * MOLAR_VOLUME_NM3
+    }
+    /// Same gas as mole (= volume) fractions of the wet flue gas
+    pub fn get_mole_fractions(&self) -> FlueGas {
+        let total = self.get_total();
+        FlueGas {
+            co2: self.co2 / total,
+            h2o: self.h2o / total,
+            so2: self.so2 / total,
+            n2: self.n2 / total,
+            o2: self.o2 / total,
+        }
+    }
+    /// Sensible enthalpy of the gas relative to 25 °C, kJ per kg of fuel
+    fn get_enthalpy_kj(&self, t: Kelvin) -> f32 {
+        self.co2 * enthalpy(&H_CO2, t)
+            + self.h2o * enthalpy(&H_H2O, t)
+            + self.so2 * enthalpy(&H_SO2, t)
+            + self.n2 * enthalpy(&H_N2, t)
+            + self.o2 * enthalpy(&H_O2, t)
+    }
+}
+
+// H(T) - H(298.15 K), kJ/kmol, JANAF tables
+const T_TABLE: [Kelvin; 7] = [298.15, 500.0, 1000.0, 1500.0, 2000.0, 2500.0, 3000.0];
+const H_N2: [f32; 7] = [0.0, 5_912.0, 21_463.0, 38_405.0, 56_137.0, 74_313.0, 92_730.0];
+const H_O2: [f32; 7] = [0.0, 6_088.0, 22_707.0, 40_610.0, 59_199.0, 78_375.0, 98_013.0];
+const H_CO2: [f32; 7] = [0.0, 8_301.0, 33_397.0, 61_705.0, 91_439.0, 121_926.0, 152_852.0];
+const H_H2O: [f32; 7] = [0.0, 6_920.0, 26_000.0, 48_151.0, 72_790.0, 98_956.0, 126_549.0];
+const H_SO2: [f32; 7] = [0.0, 8_700.0, 33_100.0, 59_600.0, 86_700.0, 114_100.0, 141_700.0];
+
+/// Linear interpolation in the enthalpy table, extrapolated past its ends
+fn enthalpy(table: &[f32; 7], t: Kelvin) -> f32 {
+    let i = T_TABLE[1..T_TABLE.len() - 1].iter().take_while(|edge| t > **edge).count();
+    let slope = (table[i + 1] - table[i]) / (T_TABLE[i + 1] - T_TABLE[i]);
+    table[i] + slope * (t - T_TABLE[i])
+}
+
+/// `moisture` and `ash` (mass %) limited to `MAX_MOISTURE_ASH_PCT` together, moisture is kept first
+pub fn limit_moisture_ash(moisture: Percent, ash: Percent) -> (Percent, Percent) {
+    let moisture = moisture.clamp(0.0, MAX_MOISTURE_ASH_PCT);
+    (moisture, ash.clamp(0.0, MAX_MOISTURE_ASH_PCT - moisture))
+}
+
+impl FuelComposition {
+    /// Same fuel with `moisture` and `ash` (mass %), the combustible part is scaled to fill the rest. <br>
+    /// Moisture and ash are limited, see `limit_moisture_ash`.
+    pub fn with_moisture_ash(&self, moisture: Percent, ash: Percent) -> FuelComposition {
+        let (moisture, ash) = limit_moisture_ash(moisture, ash);
+        let combustible = 100.0 - self.moisture - self.ash;
+        let scale = (100.0 - moisture - ash) / combustible;
+        FuelComposition {
+            carbon: self.carbon * scale,
+            hydrogen: self.hydrogen * scale,
+            oxygen: self.oxygen * scale,
+            nitrogen: self.nitrogen * scale,
+            sulfur: self.sulfur * scale,
+            moisture,
+            ash,
+        }
+    }
+    /// Oxygen needed to burn 1 kg of fuel completely, kmol/kg
+    pub fn get_stoichiometric_oxygen_kmol(&self) -> f32 {
+        let o2 = self.carbon / M_C + self.hydrogen / (2.0 * M_H2) + self.sulfur / M_S - self.oxygen / M_O2;
+        (o2 * 0.01).max(0.0)
+    }
+    /// Stoichiometric air-fuel ratio, kg of air per kg of fuel
+    pub fn get_stoichiometric_air_kg(&self) -> f32 {
+        self.get_stoichiometric_oxygen_kmol() * M_O2 / AIR_OXYGEN_MASS_FRACTION
+    }
+    /// Air supplied per kg of fuel at `excess_air` ratio λ (1.0 - stoichiometric), Nm³/kg
+    pub fn get_air_nm3(&self, excess_air: f32) -> f32 {
+        excess_air * self.get_stoichiometric_oxygen_kmol() * MOLAR_VOLUME_NM3 / AIR_OXYGEN_MOLE_FRACTION
+    }
+    /// Flue gas of complete combustion at `excess_air` ratio λ, kmol per kg of fuel. <br>
+    /// `None` for λ < 1.0: the air is not enough to burn the fuel completely.
+    pub fn get_flue_gas(&self, excess_air: f32) -> Option<FlueGas> {
+        if !(excess_air >= 1.0 && excess_air.is_finite()) {
+            return None;
+        }
+        let o2 = self.get_stoichiometric_oxygen_kmol();
+        let air_n2 = (1.0 - AIR_OXYGEN_MOLE_FRACTION) / AIR_OXYGEN_MOLE_FRACTION;
+        Some(FlueGas {
+            co2: self.carbon * 0.01 / M_C,
+            h2o: (self.hydrogen / M_H2 + self.moisture / M_H2O) * 0.01,
+            so2: self.sulfur * 0.01 / M_S,
+            n2: self.nitrogen * 0.01 / M_N2 + excess_air * o2 * air_n2,
+            o2: (excess_air - 1.0) * o2,
+        })
+    }
+    /// CO₂ emitted per MJ of heat, kg/MJ. `lhv` must be on the same basis as the composition
+    pub fn get_co2_kg_per_mj(&self, lhv: MJ_kg) -> f32 {
+        self.carbon * 0.01 / M_C * M_CO2 / lhv
+    }
+    /// Adiabatic flame temperature at `excess_air` ratio λ and combustion air at `air`. <br>
+    /// `lhv` must be on the same basis as the composition. Dissociation is ignored,
+    /// so above ~2000 °C the result is higher than the real flame. `None` for λ < 1.0.
+    pub fn get_adiabatic_flame_temperature_c(&self, lhv: MJ_kg, excess_air: f32, air: Celsius) -> Option<Celsius> {
+        let flue = self.get_flue_gas(excess_air)?;
+        let air_k = air + 273.15;
+        let air_o2 = excess_air * self.get_stoichiometric_oxygen_kmol();
+        let air_n2 = air_o2 * (1.0 - AIR_OXYGEN_MOLE_FRACTION) / AIR_OXYGEN_MOLE_FRACTION;
+        let heat = lhv * 1000.0 + air_o2 * enthalpy(&H_O2, air_k) + air_n2 * enthalpy(&H_N2, air_k);
+        // bisection on the monotonic flue gas enthalpy
+        let (mut low, mut high): (Kelvin, Kelvin) = (T_TABLE[0], 6000.0);
+        for _ in 0..60 {
+            let mid = (low + high) * 0.5;
+            if flue.get_enthalpy_kj(mid) < heat {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some((low + high) * 0.5 - 273.15)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn hydrogen() {
+        let h2 = Hydrogen.get_ultimate_analysis();
+        // 34.3 kg of air per kg of hydrogen
+        assert!((h2.get_stoichiometric_air_kg() - 34.3).abs() < 0.2);
+        let flue = h2.get_flue_gas(1.0).unwrap();
+        assert_eq!(flue.co2, 0.0);
+        assert!((flue.get_mole_fractions().h2o - 0.347).abs() < 0.005);
+        assert_eq!(h2.get_co2_kg_per_mj(Hydrogen.get_lhv_avg_mj()), 0.0);
+        let flame = h2.get_adiabatic_flame_temperature_c(Hydrogen.get_lhv_avg_mj(), 1.0, 25.0).unwrap();
+        assert!((2150.0..2400.0).contains(&flame), "Hydrogen: {}°C", flame);
+    }
+    #[test]
+    fn brown_coal() {
+        let coal = BrownCoal.get_ultimate_analysis();
+        let lhv = BrownCoal.get_lhv_avg_mj();
+        let co2 = coal.get_co2_kg_per_mj(lhv);
+        assert!((0.09..0.11).contains(&co2), "BrownCoal: {}kg/MJ", co2);
+        let flue = coal.get_flue_gas(1.4).unwrap();
+        assert!(flue.o2 > 0.0 && flue.get_dry_volume_nm3() < flue.get_volume_nm3());
+        // more air cools the flame, preheated air heats it
+        let flame = coal.get_adiabatic_flame_temperature_c(lhv, 1.2, 25.0).unwrap();
+        assert!(coal.get_adiabatic_flame_temperature_c(lhv, 1.6, 25.0).unwrap() < flame);
+        assert!(coal.get_adiabatic_flame_temperature_c(lhv, 1.2, 300.0).unwrap() > flame);
+        // too little air for complete combustion
+        assert!(coal.get_flue_gas(0.8).is_none());
+        assert!(coal.get_adiabatic_flame_temperature_c(lhv, 0.8, 25.0).is_none());
+        assert!(coal.get_flue_gas(f32::NAN).is_none());
+    }
+    #[test]
+    fn moisture_ash_limit() {
+        let coal = BrownCoal.get_ultimate_analysis();
+        let soaked = coal.with_moisture_ash(100.0, 10.0);
+        assert_eq!((soaked.moisture, soaked.ash), (combustion::MAX_MOISTURE_ASH_PCT, 0.0));
+        assert!(soaked.carbon.is_finite() && soaked.carbon > 0.0);
+        assert_eq!(combustion::limit_moisture_ash(-5.0, 30.0), (0.0, 30.0));
+        // Oak soaked through is heavy but finite
+        assert!(Oak.get_density_kgm3_at(100.0).is_finite());
+        assert_eq!(Oak.get_density_kgm3_at(100.0), Oak.get_density_kgm3_at(combustion::MAX_MOISTURE_ASH_PCT));
+        assert!(BrownCoal.get_hhv_mj_at(60.0, 40.0).is_finite());
+    }
+}
@@ -0,0 +1,92 @@
+//! Peng–Robinson cubic equation of state for real gases, compressed and cryogenic.
+//!
+//! `p = RT/(v - b) - a·α(T)/(v² + 2bv - b²)` with `a`, `b` from the critical point and `α`
+//! from the acentric factor. When the cubic has three roots (two phases possible)
+//! the one with the lower fugacity is the stable phase.
+//!
+//! Уравнение состояния Пенга — Робинсона для реальных газов.
+
+use crate::{KPa, Kelvin, GAS_CONSTANT};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Peng–Robinson parameters of a pure substance
+pub struct PengRobinson {
+    pub critical_temperature: Kelvin,
+    pub critical_pressure: KPa,
+    pub acentric_factor: f32,
+}
+
+/// Real roots of z³ + a2·z² + a1·z + a0 = 0
+fn solve_cubic(a2: f64, a1: f64, a0: f64) -> Vec<f64> {
+    // depressed cubic t³ + p·t + q = 0, z = t - a2/3
+    let p = a1 - a2 * a2 / 3.0;
+    let q = 2.0 * a2.powi(3) / 27.0 - a2 * a1 / 3.0 + a0;
+    let discriminant = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+    if discriminant > 0.0 {
+        let root = discriminant.sqrt();
+        vec![(-q / 2.0 + root).cbrt() + (-q / 2.0 - root).cbrt() - a2 / 3.0]
+    } else {
+        let radius = 2.0 * (-p / 3.0).sqrt();
+        let angle = (3.0 * q / (p * radius)).clamp(-1.0, 1.0).acos() / 3.0;
+        (0..3)
+            .map(|k| radius * (angle - 2.0 * std::f64::consts::PI * k as f64 / 3.0).cos() - a2 / 3.0)
+            .collect()
+    }
+}
+
+impl PengRobinson {
+    /// Dimensionless A = a·α·p/(RT)² and B = b·p/(RT)
+    fn get_coefficients(&self, t: Kelvin, p: KPa) -> (f64, f64) {
+        let (t, p) = (t as f64, p as f64);
+        let (tc, pc, omega) = (self.critical_temperature as f64, self.critical_pressure as f64, self.acentric_factor as f64);
+        let kappa = 0.37464 + 1.54226 * omega - 0.26992 * omega * omega;
+        let alpha = (1.0 + kappa * (1.0 - (t / tc).sqrt())).powi(2);
+        let reduced = p / pc;
+        let temperature = t / tc;
+        (0.45724 * alpha * reduced / temperature.powi(2), 0.07780 * reduced / temperature)
+    }
+    /// Compressibility factor Z = p·v/(R·T) of the stable phase at `t`, `p`. 1.0 - ideal gas
+    pub fn get_compressibility(&self, t: Kelvin, p: KPa) -> f32 {
+        let (a, b) = self.get_coefficients(t, p);
+        let roots = solve_cubic(-(1.0 - b), a - 3.0 * b * b - 2.0 * b, -(a * b - b * b - b.powi(3)));
+        let sqrt2 = std::f64::consts::SQRT_2;
+        // ln φ, the stable phase has the lowest fugacity
+        let ln_fugacity = |z: f64| {
+            z - 1.0 - (z - b).ln() - a / (2.0 * sqrt2 * b) * ((z + (1.0 + sqrt2) * b) / (z + (1.0 - sqrt2) * b)).ln()
+        };
+        roots
+            .into_iter()
+            .filter(|z| *z > b)
+            .min_by(|x, y| ln_fugacity(*x).total_cmp(&ln_fugacity(*y)))
+            .unwrap_or(1.0) as f32
+    }
+    /// Molar volume at `t`, `p`, m³/kmol
+    pub fn get_molar_volume_m3_kmol(&self, t: Kelvin, p: KPa) -> f32 {
+        self.get_compressibility(t, p) * GAS_CONSTANT * t / p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Density, Gas, Hydrogen, Methane, Propane};
+
+    #[test]
+    fn peng_robinson() {
+        // 700 bar hydrogen tank at 15 °C holds ~40 kg/m³, ideal gas says ~59
+        let (t, p) = (288.15, 70_000.0);
+        let real = Hydrogen.get_real_density_kgm3_at_tp(t, p);
+        assert!((38.0..43.0).contains(&real), "{}kg/m³", real);
+        assert!(Hydrogen.get_density_kgm3_at_tp(t, p) > 55.0);
+        assert!(Hydrogen.get_compressibility_at_tp(t, p) > 1.3);
+        // 5.6 kg in a 140 L tank of a fuel cell car
+        let mass = Hydrogen.get_stored_mass_kg(0.14, t, p);
+        assert!((5.3..6.0).contains(&mass), "{}kg", mass);
+        // at normal conditions gases are close to ideal
+        assert!((Methane.get_compressibility_at_tp(273.15, 101.325) - 0.998).abs() < 0.002);
+        let propane = Propane.get_real_density_kgm3_at_tp(273.15, 101.325);
+        assert!((propane / Propane.get_density_avg_kgm3() - 1.0).abs() < 0.01, "{}kg/m³", propane);
+        // below its boiling point propane is a liquid, ~580 kg/m³ at -50 °C
+        let liquid = Propane.get_real_density_kgm3_at_tp(223.15, 101.325);
+        assert!((500.0..650.0).contains(&liquid), "{}kg/m³", liquid);
+    }
+}
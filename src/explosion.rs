@@ -0,0 +1,104 @@
+//! Explosion energy of a fuel, its TNT equivalent and blast overpressure vs. distance.
+//!
+//! Energy is the lower heating value of the fuel, scaled by an explosion yield for
+//! the TNT equivalent. Overpressure of the blast wave comes from the Kinney–Graham
+//! fit for a TNT surface-free air burst in terms of the scaled distance `Z = R / W^(1/3)`.
+//!
+//! Энергия взрыва топлива, тротиловый эквивалент и избыточное давление ударной волны.
+
+use crate::{CubicMeter, GasHeatValue, HeatValue, KPa, Kilogram, Megajoule, Meter, MJ_kg};
+
+/// Specific energy of TNT by convention, MJ/kg
+pub const TNT_ENERGY_MJ_KG: MJ_kg = 4.184;
+/// Atmospheric pressure at sea level, kPa
+pub const ATMOSPHERIC_PRESSURE_KPA: KPa = 101.325;
+/// Share of the combustion energy turned into blast by an unconfined vapour cloud,
+/// conservative value of the TNT-equivalence method
+pub const VAPOUR_CLOUD_YIELD: f32 = 0.1;
+
+/// Heat released by burning `mass` of the fuel completely, MJ (lower heating value)
+pub fn get_combustion_energy_mj(fuel: &dyn HeatValue, mass: Kilogram) -> Megajoule {
+    fuel.get_lhv_avg_mj() * mass
+}
+
+/// Heat released by burning `volume` of a gas fuel at the density it is tabulated for, MJ. <br>
+/// See `Density::get_density_conditions`, gases are tabulated at `ReferenceConditions::STP`.
+pub fn get_combustion_energy_mj_by_volume(fuel: &dyn GasHeatValue, volume: CubicMeter) -> Megajoule {
+    fuel.get_lhv_avg_mj_m3() * volume
+}
+
+/// Mass of TNT releasing the same blast energy as `mass` of the fuel, kg. <br>
+/// `explosion_yield` - share of the combustion energy that goes into the blast,
+/// 1.0 for a detonation of the whole mass, see `VAPOUR_CLOUD_YIELD`.
+pub fn get_tnt_equivalent_kg(fuel: &dyn HeatValue, mass: Kilogram, explosion_yield: f32) -> Kilogram {
+    explosion_yield * get_combustion_energy_mj(fuel, mass) / TNT_ENERGY_MJ_KG
+}
+
+/// Scaled (Hopkinson–Cranz) distance Z = R / W^(1/3), m/kg^(1/3)
+pub fn get_scaled_distance(tnt: Kilogram, distance: Meter) -> f32 {
+    distance / tnt.cbrt()
+}
+
+/// Peak side-on overpressure at `distance` from the explosion of `tnt` kg of TNT, kPa (Kinney–Graham). <br>
+/// 0 without any TNT.
+pub fn get_overpressure_kpa(tnt: Kilogram, distance: Meter) -> KPa {
+    if tnt <= 0.0 {
+        return 0.0;
+    }
+    let z = get_scaled_distance(tnt, distance);
+    let ratio = 808.0 * (1.0 + (z / 4.5).powi(2))
+        / ((1.0 + (z / 0.048).powi(2)) * (1.0 + (z / 0.32).powi(2)) * (1.0 + (z / 1.35).powi(2))).sqrt();
+    ATMOSPHERIC_PRESSURE_KPA * ratio
+}
+
+/// Distance at which the overpressure from `tnt` kg of TNT drops to `overpressure`, m. <br>
+/// Use it for safety distances, e.g. ~1 kPa - windows break, ~7 kPa - houses are damaged.
+/// 0 without any TNT.
+pub fn get_distance_for_overpressure_m(tnt: Kilogram, overpressure: KPa) -> Meter {
+    if tnt <= 0.0 {
+        return 0.0;
+    }
+    // Kinney–Graham decreases with distance, bisect on the scaled distance
+    let (mut low, mut high) = (1.0e-3_f32, 1.0e4_f32);
+    for _ in 0..100 {
+        let middle = (low * high).sqrt();
+        if get_overpressure_kpa(1.0, middle) > overpressure {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low * high).sqrt() * tnt.cbrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hydrogen, Material};
+
+    #[test]
+    fn tnt_equivalent() {
+        // 1 kg of hydrogen holds as much energy as ~29 kg of TNT
+        let tnt = get_tnt_equivalent_kg(&Hydrogen, 1.0, 1.0);
+        assert!((tnt - 120.0 / 4.184).abs() < 0.1, "{}kg", tnt);
+        // 1 m³ of hydrogen at normal conditions is ~90 g
+        let energy = get_combustion_energy_mj_by_volume(&Hydrogen, 1.0);
+        assert!((energy - 10.8).abs() < 0.1, "{}MJ", energy);
+        let hydrogen: &dyn Material = &Hydrogen;
+        assert_eq!(hydrogen.as_gas_heat_value().map(|fuel| get_combustion_energy_mj_by_volume(fuel, 1.0)), Some(energy));
+        assert!((get_tnt_equivalent_kg(&Hydrogen, 1.0, VAPOUR_CLOUD_YIELD) - tnt * 0.1).abs() < 1e-3);
+    }
+    #[test]
+    fn overpressure() {
+        // tabulated Kinney–Graham values
+        assert!((get_overpressure_kpa(1.0, 10.0) - 10.0).abs() < 0.2);
+        assert!((get_overpressure_kpa(8.0, 10.0) - 29.24).abs() < 0.2);
+        assert!(get_overpressure_kpa(100.0, 50.0) > get_overpressure_kpa(100.0, 100.0));
+        let distance = get_distance_for_overpressure_m(29.0, 7.0);
+        assert!((get_overpressure_kpa(29.0, distance) - 7.0).abs() < 0.01, "{}m", distance);
+        // no TNT - no blast
+        assert_eq!(get_overpressure_kpa(0.0, 10.0), 0.0);
+        assert_eq!(get_overpressure_kpa(-1.0, 10.0), 0.0);
+        assert_eq!(get_distance_for_overpressure_m(0.0, 7.0), 0.0);
+    }
+}
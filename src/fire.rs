@@ -0,0 +1,324 @@
+//! Deterministic cellular fire spread on a rectangular grid of materials.
+//!
+//! Each cell holds a material and its mass. A burning cell releases `HeatValue` heat,
+//! part of which heats its 4 neighbours; a cell ignites once it is past its `Ignition`
+//! temperature and burns until its mass is gone.
+//!
+//! Клеточная модель распространения огня.
+
+use crate::{Celsius, Kelvin, Kilogram, Material, Second};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FireState {
+    /// Not burning yet, or does not burn at all.<br>
+    /// Не горит.
+    Unburnt,
+    /// Burning.<br>
+    /// Горит.
+    Burning,
+    /// All fuel is burnt.<br>
+    /// Выгорело.
+    BurntOut,
+}
+
+#[derive(Clone, Copy)]
+pub struct FireCell {
+    /// `None` - empty cell, heat does not pass through it
+    pub material: Option<&'static dyn Material>,
+    pub mass: Kilogram,
+    pub temperature: Kelvin,
+    pub state: FireState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FireConfig {
+    /// Temperature of the surroundings and of new cells, K
+    pub ambient: Kelvin,
+    /// Specific heat used for every cell, J/(kg·K)
+    pub heat_capacity: f32,
+    /// Mass burnt by a burning cell, kg/s
+    pub burn_rate: f32,
+    /// Share of the released heat passed to the neighbours, split between the 4 of them
+    pub transfer: f32,
+    /// Heat conductance between two neighbouring cells, W/K
+    pub conductance: f32,
+    /// Newton cooling rate towards `ambient`, 1/s
+    pub cooling: f32,
+}
+
+impl Default for FireConfig {
+    fn default() -> Self {
+        FireConfig {
+            ambient: 293.15,
+            heat_capacity: 1500.0,
+            burn_rate: 0.05,
+            transfer: 0.2,
+            conductance: 5.0,
+            cooling: 0.005,
+        }
+    }
+}
+
+/// State of the grid after a tick
+pub struct FireFrame {
+    pub tick: u64,
+    /// Row-major temperatures of all cells, °C
+    pub temperatures: Vec<Celsius>,
+    /// Cells burning after the tick
+    pub burning: usize,
+}
+
+pub struct FireGrid {
+    width: usize,
+    height: usize,
+    cells: Vec<FireCell>,
+    config: FireConfig,
+    tick: u64,
+}
+
+impl FireGrid {
+    /// Grid of empty cells at ambient temperature
+    pub fn new(width: usize, height: usize, config: FireConfig) -> Self {
+        let empty = FireCell {material: None, mass: 0.0, temperature: config.ambient, state: FireState::Unburnt};
+        FireGrid {width, height, cells: vec![empty; width * height], config, tick: 0}
+    }
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+    /// `None` outside the grid
+    pub fn get(&self, x: usize, y: usize) -> Option<&FireCell> {
+        self.get_index(x, y).map(|index| &self.cells[index])
+    }
+    /// Puts `mass` of `material` at ambient temperature into the cell.
+    /// Returns `false` outside the grid or for a negative or non-finite `mass`
+    pub fn set(&mut self, x: usize, y: usize, material: &'static dyn Material, mass: Kilogram) -> bool {
+        let Some(index) = self.get_index(x, y) else {return false};
+        if !(mass >= 0.0 && mass.is_finite()) {
+            return false;
+        }
+        self.cells[index] = FireCell {
+            material: Some(material),
+            mass,
+            temperature: self.config.ambient,
+            state: FireState::Unburnt,
+        };
+        true
+    }
+    /// Sets the cell on fire if its material burns. Returns `true` if it is burning,
+    /// `false` outside the grid
+    pub fn ignite(&mut self, x: usize, y: usize) -> bool {
+        let Some(index) = self.get_index(x, y) else {return false};
+        if Self::is_combustible(&self.cells[index]) {
+            Self::start_burning(&mut self.cells[index]);
+        }
+        self.cells[index].state == FireState::Burning
+    }
+    /// Row-major temperatures of all cells, °C
+    pub fn get_temperatures_c(&self) -> Vec<Celsius> {
+        self.cells.iter().map(|cell| cell.temperature - 273.15).collect()
+    }
+
+    fn get_index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+    fn is_combustible(cell: &FireCell) -> bool {
+        cell.state == FireState::Unburnt
+            && cell.mass > 0.0
+            && cell.material.is_some_and(|material| material.as_ignition().is_some() && material.as_heat_value().is_some())
+    }
+    fn start_burning(cell: &mut FireCell) {
+        cell.state = FireState::Burning;
+        if let Some(burning) = cell.material.and_then(|material| material.as_burning()) {
+            cell.temperature = cell.temperature.max(burning.get_burning_avg_k());
+        }
+    }
+    fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> {
+        let (x, y, width, height) = (index % self.width, index / self.width, self.width, self.height);
+        [
+            (x > 0).then(|| index - 1),
+            (x + 1 < width).then(|| index + 1),
+            (y > 0).then(|| index - width),
+            (y + 1 < height).then(|| index + width),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Advances the simulation by `dt`. All cells are updated from the previous state,
+    /// so the result does not depend on the cell order. <br>
+    /// `None` and nothing changes if `dt` is not positive or not finite.
+    pub fn step(&mut self, dt: Second) -> Option<FireFrame> {
+        if !(dt > 0.0 && dt.is_finite()) {
+            return None;
+        }
+        let config = self.config;
+        // heat gained by each cell during the tick, J
+        let mut heat = vec![0.0_f32; self.cells.len()];
+        let mut burnt = vec![0.0_f32; self.cells.len()];
+        for (index, cell) in self.cells.iter().enumerate() {
+            let Some(material) = cell.material else {continue};
+            if cell.state == FireState::Burning {
+                let lhv = material.as_heat_value().map_or(0.0, |heat_value| heat_value.get_lhv_avg_mj());
+                burnt[index] = cell.mass.min(config.burn_rate * dt);
+                let released = burnt[index] * lhv * 1.0e6;
+                for neighbour in self.neighbours(index) {
+                    if self.cells[neighbour].material.is_some() {
+                        heat[neighbour] += released * config.transfer * 0.25;
+                    }
+                }
+            }
+            // burnt-out cells have no mass left and take no part in conduction
+            if cell.mass <= 0.0 {
+                continue;
+            }
+            for neighbour in self.neighbours(index) {
+                let other = &self.cells[neighbour];
+                if other.material.is_some() && other.mass > 0.0 {
+                    let (capacity, other_capacity) = (cell.mass * config.heat_capacity, other.mass * config.heat_capacity);
+                    // explicit step stays stable while a cell takes at most a quarter of the heat
+                    // that evens out the pair from each of its 4 neighbours
+                    let limit = capacity * other_capacity / (capacity + other_capacity) * 0.25;
+                    heat[index] += (config.conductance * dt).min(limit) * (other.temperature - cell.temperature);
+                }
+            }
+        }
+        let mut burning = 0;
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            let Some(material) = cell.material else {continue};
+            cell.mass -= burnt[index];
+            if cell.state == FireState::Burning {
+                if cell.mass <= 0.0 {
+                    cell.mass = 0.0;
+                    cell.state = FireState::BurntOut;
+                } else {
+                    burning += 1;
+                    continue;
+                }
+            }
+            if cell.mass > 0.0 {
+                cell.temperature += heat[index] / (cell.mass * config.heat_capacity);
+            }
+            cell.temperature += (config.ambient - cell.temperature) * (config.cooling * dt).min(1.0);
+            let ignition = material.as_ignition().map(|ignition| ignition.get_ignition_avg_k());
+            if Self::is_combustible(cell) && ignition.is_some_and(|ignition| cell.temperature >= ignition) {
+                Self::start_burning(cell);
+                burning += 1;
+            }
+        }
+        self.tick += 1;
+        Some(FireFrame {tick: self.tick, temperatures: self.get_temperatures_c(), burning})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Granite, Oak};
+
+    fn row() -> FireGrid {
+        let mut grid = FireGrid::new(7, 1, FireConfig::default());
+        for x in 0..5 {
+            grid.set(x, 0, &Oak, 10.0);
+        }
+        // x = 5 stays empty
+        grid.set(6, 0, &Oak, 10.0);
+        grid
+    }
+
+    #[test]
+    fn spread() {
+        let mut grid = row();
+        assert!(grid.ignite(0, 0));
+        let mut last = None;
+        for _ in 0..5000 {
+            last = grid.step(1.0);
+        }
+        for x in 0..5 {
+            assert_eq!(grid.get(x, 0).unwrap().state, FireState::BurntOut, "cell {}", x);
+        }
+        // fire does not jump over an empty cell
+        assert_eq!(grid.get(6, 0).unwrap().state, FireState::Unburnt);
+        assert_eq!(last.map(|frame| frame.tick), Some(5000));
+    }
+    #[test]
+    fn stone_does_not_burn() {
+        let mut grid = FireGrid::new(2, 1, FireConfig::default());
+        grid.set(0, 0, &Oak, 10.0);
+        grid.set(1, 0, &Granite, 50.0);
+        assert!(!grid.ignite(1, 0));
+        assert!(grid.ignite(0, 0));
+        let frame = grid.step(10.0).unwrap();
+        assert_eq!(frame.burning, 1);
+        assert!(frame.temperatures[1] > 20.0);
+        assert_eq!(grid.get(1, 0).unwrap().state, FireState::Unburnt);
+    }
+    #[test]
+    fn out_of_grid() {
+        let mut grid = FireGrid::new(2, 2, FireConfig::default());
+        assert!(!grid.set(2, 0, &Oak, 1.0));
+        assert!(!grid.ignite(0, 2));
+        assert!(grid.get(2, 0).is_none());
+        // (0, 1) is not touched by writing past the row end
+        assert!(grid.get(0, 1).unwrap().material.is_none());
+        // no negative or unknown mass
+        assert!(!grid.set(0, 0, &Oak, -1.0));
+        assert!(!grid.set(0, 0, &Oak, f32::NAN));
+        assert!(grid.get(0, 0).unwrap().material.is_none());
+    }
+    #[test]
+    fn invalid_step() {
+        let mut grid = row();
+        grid.ignite(0, 0);
+        let before = grid.get_temperatures_c();
+        for dt in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(grid.step(dt).is_none());
+        }
+        assert_eq!(grid.get_tick(), 0);
+        assert_eq!(grid.get_temperatures_c(), before);
+        assert_eq!(grid.get(0, 0).unwrap().mass, 10.0);
+    }
+    #[test]
+    fn conduction_is_stable() {
+        // light cells, strong conductance and a long tick would blow up an unlimited explicit step
+        let config = FireConfig {conductance: 1000.0, cooling: 0.0, ..FireConfig::default()};
+        let mut grid = FireGrid::new(3, 1, config);
+        for x in 0..3 {
+            grid.set(x, 0, &Granite, 0.01);
+        }
+        grid.cells[0].temperature = 1000.0;
+        let total: f32 = grid.cells.iter().map(|cell| cell.temperature).sum();
+        for _ in 0..100 {
+            let frame = grid.step(10.0).unwrap();
+            assert!(frame.temperatures.iter().all(|t| (19.0..727.0).contains(t)), "{:?}", frame.temperatures);
+        }
+        // heat is conserved and evens out
+        let temperatures: Vec<f32> = grid.cells.iter().map(|cell| cell.temperature).collect();
+        assert!((temperatures.iter().sum::<f32>() - total).abs() < 0.1);
+        assert!((temperatures[0] - temperatures[2]).abs() < 1.0);
+    }
+    #[test]
+    fn burnt_out_cells_do_not_conduct() {
+        let mut grid = FireGrid::new(2, 1, FireConfig {cooling: 0.0, ..FireConfig::default()});
+        grid.set(0, 0, &Granite, 10.0);
+        grid.set(1, 0, &Oak, 0.0);
+        grid.cells[1].state = FireState::BurntOut;
+        grid.cells[1].temperature = 1500.0;
+        grid.step(10.0);
+        assert_eq!(grid.get(0, 0).unwrap().temperature, FireConfig::default().ambient);
+    }
+    #[test]
+    fn deterministic() {
+        let (mut a, mut b) = (row(), row());
+        a.ignite(0, 0);
+        b.ignite(0, 0);
+        for _ in 0..300 {
+            assert_eq!(a.step(0.5).unwrap().temperatures, b.step(0.5).unwrap().temperatures);
+        }
+    }
+}
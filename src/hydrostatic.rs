@@ -0,0 +1,102 @@
+//! Hydrostatic (Archimedes) weighing: density of a sample from its mass in air and
+//! in water, the materials it can be made of, and the gold content of a gold alloy.
+//!
+//! `ρ = m_air·(ρ_water - ρ_air)/(m_air - m_water) + ρ_air`, the scale is calibrated
+//! with weights in air so the air buoyancy is corrected for. The gold content assumes
+//! a gold alloy of two metals whose volumes add up.
+//!
+//! Гидростатическое взвешивание: плотность образца, подходящие материалы и проба золота.
+
+use crate::precious::Fineness;
+use crate::water::get_liquid_density_kgm3;
+use crate::{Air, Celsius, Density, Gas, Gold, KGm3, Kilogram, Material, Percent, ALL_MATERIALS};
+
+/// Density of a sample weighing `mass_air` in air and `mass_water` fully immersed in
+/// water at `water` temperature, kg/m³. <br>
+/// `None` if the sample does not weigh less in water than in air, or the masses are not finite.
+/// A floating sample held under water weighs less than zero.
+pub fn get_density_by_weighing_kgm3(mass_air: Kilogram, mass_water: Kilogram, water: Celsius) -> Option<KGm3> {
+    if !(mass_air > 0.0 && mass_water < mass_air && mass_air.is_finite() && mass_water.is_finite()) {
+        return None;
+    }
+    let water_density = get_liquid_density_kgm3(water, 101.325);
+    let air_density = Air.get_density_kgm3_at_tp(water + 273.15, 101.325);
+    Some(mass_air * (water_density - air_density) / (mass_air - mass_water) + air_density)
+}
+
+/// Materials whose density range, widened by `tolerance` % on both sides, contains `density`,
+/// the closest average density first
+pub fn find_materials_by_density(density: KGm3, tolerance: Percent) -> Vec<&'static dyn Material> {
+    let gcm3 = density * 1.0e-3;
+    let widen = tolerance * 0.01;
+    let mut found: Vec<(&'static dyn Material, f32)> = ALL_MATERIALS
+        .iter()
+        .filter_map(|material| {
+            let range = material.as_density()?;
+            let (min, max) = (range.get_density_min_gcm3() * (1.0 - widen), range.get_density_max_gcm3() * (1.0 + widen));
+            (min..=max).contains(&gcm3).then(|| (*material, (range.get_density_avg_gcm3() - gcm3).abs()))
+        })
+        .collect();
+    found.sort_by(|a, b| a.1.total_cmp(&b.1));
+    found.into_iter().map(|(material, _)| material).collect()
+}
+
+/// Gold content of an alloy of `Gold` and `ligature` metal with the measured `density`, kg/m³. <br>
+/// `None` if the density is not finite or outside the densities of the two metals, or the
+/// ligature is as dense as gold within the measurement error.
+pub fn get_gold_content(density: KGm3, ligature: &dyn Density) -> Option<Fineness> {
+    let gold = Gold.get_density_avg_kgm3();
+    let other = ligature.get_density_avg_kgm3();
+    let (low, high) = (gold.min(other), gold.max(other));
+    // allow 0.5% of measurement error at the ends of the range
+    if !density.is_finite() || density < low * 0.995 || density > high * 1.005 || high * 0.995 <= low {
+        return None;
+    }
+    // specific volumes add up: 1/ρ = w/ρ_gold + (1 - w)/ρ_other
+    let share = ((1.0 / density - 1.0 / other) / (1.0 / gold - 1.0 / other)).clamp(0.0, 1.0);
+    Some(Fineness {millesimal: share * 1000.0})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Copper, SLang};
+
+    #[test]
+    fn weighing() {
+        // 100 g of gold weighs ~94.83 g under water at 20 °C
+        let density = get_density_by_weighing_kgm3(0.1, 0.1 - 0.1 * 998.2 / 19_310.0, 20.0).unwrap();
+        assert!((density - 19_310.0).abs() < 30.0, "{}kg/m³", density);
+        let found = find_materials_by_density(density, 0.5);
+        assert_eq!(found.first().map(|material| material.get_name(SLang::EN)), Some("Gold"));
+        assert!(find_materials_by_density(8_600.0, 0.0).iter().any(|material| material.get_name(SLang::EN) == "Brass"));
+        assert!(find_materials_by_density(50_000.0, 1.0).is_empty());
+        // oak held under water floats up
+        let oak = get_density_by_weighing_kgm3(0.1, -0.05, 20.0).unwrap();
+        assert!((660.0..670.0).contains(&oak), "{}kg/m³", oak);
+    }
+    #[test]
+    fn weighing_invalid() {
+        // the same weight in water - no volume
+        assert_eq!(get_density_by_weighing_kgm3(0.1, 0.1, 20.0), None);
+        // heavier in water than in air
+        assert_eq!(get_density_by_weighing_kgm3(0.1, 0.2, 20.0), None);
+        assert_eq!(get_density_by_weighing_kgm3(0.0, -0.1, 20.0), None);
+        assert_eq!(get_density_by_weighing_kgm3(f32::NAN, 0.05, 20.0), None);
+    }
+    #[test]
+    fn gold_content() {
+        let pure = get_gold_content(Gold.get_density_avg_kgm3(), &Copper).unwrap();
+        assert!((pure.millesimal - 1000.0).abs() < 0.1);
+        // 18 karat red gold, 75% gold and 25% copper, ~15.2 g/cm³
+        let red = 1.0 / (0.75 / Gold.get_density_avg_kgm3() + 0.25 / Copper.get_density_avg_kgm3());
+        let content = get_gold_content(red, &Copper).unwrap();
+        assert!((content.millesimal - 750.0).abs() < 0.5 && (content.get_karat() - 18.0).abs() < 0.05);
+        // denser than gold or lighter than copper - not a gold-copper alloy
+        assert!(get_gold_content(19_600.0, &Copper).is_none());
+        assert!(get_gold_content(7_000.0, &Copper).is_none());
+        assert!(get_gold_content(f32::NAN, &Copper).is_none());
+        // gold as its own ligature tells nothing about the content
+        assert!(get_gold_content(Gold.get_density_avg_kgm3(), &Gold).is_none());
+    }
+}
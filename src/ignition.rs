@@ -0,0 +1,182 @@
+//! Time to piloted ignition of a solid under a constant incident heat flux.
+//!
+//! The material is a thermally thick (semi-infinite) solid with its `Thermal` inertia λ·ρ·c.
+//! Convective and radiative losses from the surface are linearized around the ignition
+//! temperature (around the steady surface temperature for materials that do not burn),
+//! which gives the closed-form surface temperature
+//! `Ts = T0 + q/h·(1 - exp(β²)·erfc(β))`, `β = h·√(t/(λ·ρ·c))`.
+//! The material ignites once `Ts` reaches its `Ignition` temperature.
+//!
+//! Время воспламенения материала под тепловым потоком.
+
+use crate::{Celsius, Material, Second, Wm2, STEFAN_BOLTZMANN};
+
+/// Convective heat transfer coefficient of the exposed surface, W/(m²·K)
+pub const CONVECTION_COEFFICIENT: f32 = 10.0;
+/// Surface emissivity for materials without `Emissivity` data
+pub const EMISSIVITY_DEFAULT: f32 = 0.9;
+/// Most time steps `get_ignition_curve` samples
+pub const MAX_CURVE_STEPS: f32 = 1.0e6;
+
+/// Surface temperature history of an exposed material
+pub struct IgnitionCurve {
+    pub times: Vec<Second>,
+    /// Surface temperature at each of `times`, °C
+    pub surface_temperatures: Vec<Celsius>,
+    /// `None` - did not ignite during the exposure
+    pub ignition_time: Option<Second>,
+}
+
+/// exp(x²)·erfc(x) for x ≥ 0, Abramowitz & Stegun 7.1.26
+fn erfcx(x: f32) -> f32 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    t * (0.254_829_6 + t * (-0.284_496_7 + t * (1.421_413_7 + t * (-1.453_152 + t * 1.061_405_4))))
+}
+
+fn get_emissivity(material: &dyn Material) -> f32 {
+    material.as_emissivity().map_or(EMISSIVITY_DEFAULT, |emissivity| emissivity.get_emissivity_avg())
+}
+
+/// Heat lost by the surface at `surface` temperature to the `ambient`, W/m²
+fn get_heat_loss_wm2(emissivity: f32, surface: Celsius, ambient: Celsius) -> Wm2 {
+    let (ts, t0) = (surface + 273.15, ambient + 273.15);
+    CONVECTION_COEFFICIENT * (ts - t0) + emissivity * STEFAN_BOLTZMANN * (ts.powi(4) - t0.powi(4))
+}
+
+/// Surface temperature the material settles at under `heat_flux`, °C
+pub fn get_steady_surface_temperature_c(material: &dyn Material, heat_flux: Wm2, ambient: Celsius) -> Celsius {
+    let emissivity = get_emissivity(material);
+    // radiation only adds to the losses, so convection alone bounds the temperature
+    let (mut low, mut high) = (ambient, ambient + heat_flux.max(0.0) / CONVECTION_COEFFICIENT);
+    for _ in 0..60 {
+        let middle = (low + high) * 0.5;
+        if get_heat_loss_wm2(emissivity, middle, ambient) < heat_flux {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) * 0.5
+}
+
+/// Lowest heat flux that can ignite the material at all, W/m². <br>
+/// `None` if the material has no `Ignition` temperature.
+pub fn get_critical_heat_flux_wm2(material: &dyn Material, ambient: Celsius) -> Option<Wm2> {
+    let ignition = material.as_ignition()?.get_ignition_avg_c();
+    Some(get_heat_loss_wm2(get_emissivity(material), ignition, ambient))
+}
+
+/// Surface temperature after `time` seconds under `heat_flux`, °C. <br>
+/// `None` if the material has no `Thermal` data.
+pub fn get_surface_temperature_c(material: &dyn Material, heat_flux: Wm2, ambient: Celsius, time: Second) -> Option<Celsius> {
+    let inertia = material.as_thermal()?.get_thermal_inertia();
+    if heat_flux <= 0.0 {
+        return Some(ambient);
+    }
+    // total (convective + radiative) heat transfer coefficient, W/(m²·K)
+    let ignition = material.as_ignition().map(|ignition| ignition.get_ignition_avg_c()).filter(|ignition| *ignition > ambient);
+    let h = match ignition {
+        Some(ignition) => get_heat_loss_wm2(get_emissivity(material), ignition, ambient) / (ignition - ambient),
+        None => heat_flux / (get_steady_surface_temperature_c(material, heat_flux, ambient) - ambient).max(f32::EPSILON),
+    };
+    let beta = h * (time.max(0.0) / inertia).sqrt();
+    Some(ambient + heat_flux / h * (1.0 - erfcx(beta)))
+}
+
+/// Time until the surface reaches the `Ignition` temperature under `heat_flux`, s. <br>
+/// `None` if the material lacks `Thermal` or `Ignition` data, or the flux is not finite or
+/// not above `get_critical_heat_flux_wm2`.
+pub fn get_time_to_ignition_s(material: &dyn Material, heat_flux: Wm2, ambient: Celsius) -> Option<Second> {
+    let ignition = material.as_ignition()?.get_ignition_avg_c();
+    material.as_thermal()?;
+    if ignition <= ambient {
+        return Some(0.0);
+    }
+    if !heat_flux.is_finite() || heat_flux <= get_critical_heat_flux_wm2(material, ambient)? {
+        return None;
+    }
+    let surface = |time| get_surface_temperature_c(material, heat_flux, ambient, time).unwrap_or(ambient);
+    let mut high: Second = 1.0;
+    while surface(high) < ignition {
+        high *= 2.0;
+        if high > 1.0e9 {
+            return None;
+        }
+    }
+    let mut low = 0.0;
+    for _ in 0..60 {
+        let middle = (low + high) * 0.5;
+        if surface(middle) < ignition {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(high)
+}
+
+/// Surface temperature every `dt` seconds during an exposure of `duration` seconds. <br>
+/// `None` if the material has no `Thermal` data, `dt` is not positive, `duration` is negative
+/// or the exposure takes more than `MAX_CURVE_STEPS` steps.
+pub fn get_ignition_curve(material: &dyn Material, heat_flux: Wm2, ambient: Celsius, dt: Second, duration: Second) -> Option<IgnitionCurve> {
+    material.as_thermal()?;
+    if !(dt > 0.0 && dt.is_finite() && duration >= 0.0 && duration.is_finite() && duration / dt <= MAX_CURVE_STEPS) {
+        return None;
+    }
+    let ignition_time = get_time_to_ignition_s(material, heat_flux, ambient).filter(|time| *time <= duration);
+    let steps = (duration / dt).ceil() as usize;
+    let times: Vec<Second> = (0..=steps).map(|step| (step as f32 * dt).min(duration)).collect();
+    let surface_temperatures = times
+        .iter()
+        .map(|time| get_surface_temperature_c(material, heat_flux, ambient, *time).unwrap_or(ambient))
+        .collect();
+    Some(IgnitionCurve {times, surface_temperatures, ignition_time})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Granite, Ignition, Oak, Spruce, Thermal};
+
+    #[test]
+    fn time_to_ignition() {
+        let critical = get_critical_heat_flux_wm2(&Oak, 20.0).unwrap();
+        assert!((5_000.0..15_000.0).contains(&critical), "Oak: {}W/m²", critical);
+        assert_eq!(get_time_to_ignition_s(&Oak, critical * 0.9, 20.0), None);
+        assert_eq!(get_time_to_ignition_s(&Oak, f32::NAN, 20.0), None);
+        assert_eq!(get_time_to_ignition_s(&Oak, f32::INFINITY, 20.0), None);
+        let oak = get_time_to_ignition_s(&Oak, 50_000.0, 20.0).unwrap();
+        assert!((3.0..60.0).contains(&oak), "Oak: {}s", oak);
+        // more flux - faster ignition, lighter wood - faster ignition
+        assert!(get_time_to_ignition_s(&Oak, 25_000.0, 20.0).unwrap() > oak);
+        assert!(get_time_to_ignition_s(&Spruce, 50_000.0, 20.0).unwrap() < oak);
+        // high flux tends to the loss-free solution t = π/4·λρc·(ΔT/q)²
+        let flux = 1.0e6;
+        let ignition = Oak.get_ignition_avg_c();
+        let ideal = std::f32::consts::FRAC_PI_4 * Oak.get_thermal_inertia() * ((ignition - 20.0) / flux).powi(2);
+        let time = get_time_to_ignition_s(&Oak, flux, 20.0).unwrap();
+        assert!((time / ideal - 1.0).abs() < 0.05, "{} vs {}", time, ideal);
+        // stone heats up but never ignites
+        assert_eq!(get_time_to_ignition_s(&Granite, 50_000.0, 20.0), None);
+        let stone = get_surface_temperature_c(&Granite, 50_000.0, 20.0, 600.0).unwrap();
+        let steady = get_steady_surface_temperature_c(&Granite, 50_000.0, 20.0);
+        assert!(stone > 20.0 && stone < steady);
+        assert!((get_surface_temperature_c(&Granite, 50_000.0, 20.0, 1.0e9).unwrap() - steady).abs() < 5.0);
+    }
+    #[test]
+    fn ignition_curve() {
+        let curve = get_ignition_curve(&Oak, 30_000.0, 20.0, 1.0, 120.0).unwrap();
+        assert_eq!(curve.times.len(), 121);
+        assert!(curve.surface_temperatures.windows(2).all(|pair| pair[1] >= pair[0]));
+        let ignition_time = curve.ignition_time.unwrap();
+        let index = ignition_time.ceil() as usize;
+        assert!(curve.surface_temperatures[index] >= Oak.get_ignition_avg_c() - 0.1);
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, 1.0, 1.0).unwrap().ignition_time.is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, 0.0, 120.0).is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, -1.0, 120.0).is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, f32::NAN, 120.0).is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, 1.0, f32::INFINITY).is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, f32::MIN_POSITIVE, 120.0).is_none());
+        assert!(get_ignition_curve(&Oak, 30_000.0, 20.0, 120.0 / MAX_CURVE_STEPS, 120.0).is_some());
+    }
+}
@@ -26,6 +26,96 @@ type MJ_kg = f32;
 /// ккал/кг
 #[allow(non_camel_case_types)]
 type kkal_kg = f32;
+/// BTU/lb
+/// БТЕ/фунт
+#[allow(non_camel_case_types)]
+type BTU_lb = f32;
+/// MJ/m³
+/// МДж/м³
+#[allow(non_camel_case_types)]
+type MJ_m3 = f32;
+/// kcal/kg in 1 MJ/kg (international table calorie)
+pub const KKAL_KG_IN_MJ_KG: f32 = 238.845_9;
+/// BTU/lb in 1 MJ/kg (international table BTU)
+pub const BTU_LB_IN_MJ_KG: f32 = 429.922_6;
+/// Nano-ohm metre, nΩ·m <br>
+/// Наноом-метр, нОм·м
+type NOhmM = f32;
+/// Ohm, Ω <br>
+/// Ом, Ом
+type Ohm = f32;
+/// Volt, V <br>
+/// Вольт, В
+type Volt = f32;
+/// Ampere, A <br>
+/// Ампер, А
+type Ampere = f32;
+/// Metre, m <br>
+/// Метр, м
+type Meter = f32;
+/// mm², cross-section of a wire <br>
+/// мм², сечение провода
+type Mm2 = f32;
+/// 1/K, relative change per kelvin <br>
+/// 1/К, относительное изменение на кельвин
+type PerKelvin = f32;
+/// Kilogram, kg <br>
+/// Килограмм, кг
+type Kilogram = f32;
+/// Second, s <br>
+/// Секунда, с
+type Second = f32;
+/// Percent, % <br>
+/// Процент, %
+type Percent = f32;
+/// Watt per m², W/m² <br>
+/// Ватт на м², Вт/м²
+type Wm2 = f32;
+/// Watt per metre-kelvin, W/(m·K) - thermal conductivity <br>
+/// Ватт на метр-кельвин, Вт/(м·К) - теплопроводность
+type WmK = f32;
+/// Joule per kilogram-kelvin, J/(kg·K) - specific heat <br>
+/// Джоуль на килограмм-кельвин, Дж/(кг·К) - удельная теплоёмкость
+type JkgK = f32;
+/// Megajoule, MJ <br>
+/// Мегаджоуль, МДж
+type Megajoule = f32;
+/// Gram per mole, g/mol <br>
+/// Грамм на моль, г/моль
+type GMol = f32;
+/// Pascal-second, Pa·s - dynamic viscosity <br>
+/// Паскаль-секунда, Па·с - динамическая вязкость
+#[allow(non_camel_case_types)]
+type Pa_s = f32;
+/// Newton per metre, N/m - surface tension <br>
+/// Ньютон на метр, Н/м - поверхностное натяжение
+#[allow(non_camel_case_types)]
+type N_m = f32;
+/// Kilojoule per kilogram, kJ/kg - latent heat <br>
+/// Килоджоуль на килограмм, кДж/кг - теплота фазового перехода
+#[allow(non_camel_case_types)]
+type KJ_kg = f32;
+/// Newton, N <br>
+/// Ньютон, Н
+type Newton = f32;
+/// Kilopascal, kPa <br>
+/// Килопаскаль, кПа
+type KPa = f32;
+/// Cubic metre, m³ <br>
+/// Кубический метр, м³
+type CubicMeter = f32;
+/// Millijoule, mJ <br>
+/// Миллиджоуль, мДж
+type MilliJoule = f32;
+/// Gigapascal, GPa <br>
+/// Гигапаскаль, ГПа
+type GPa = f32;
+/// Metre per second, m/s <br>
+/// Метр в секунду, м/с
+type Ms = f32;
+/// Rayl, kg/(m²·s) - acoustic impedance <br>
+/// Рэлей, кг/(м²·с) - акустический импеданс
+type Rayl = f32;
 
 macro_rules! new_elem {
     (
@@ -38,19 +128,74 @@ macro_rules! new_elem {
         element: [$number:expr, $symbol:literal],
         // example_1 - dencity_gsm3: [1.1, 1.5]
         dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
+        // example_1 - gas: [molar_mass_g_mol: 2.016, critical: [t_k: 33.19, p_kpa: 1296.4], acentric: -0.216]
+        $(gas:[molar_mass_g_mol: $molar_mass:expr, critical: [t_k: $critical_t:expr, p_kpa: $critical_p:expr], acentric: $acentric:expr],)?
         // example_1 - melting_c: [1450.0, 1500.0]
         $(melting_c:[$melting_min_c:expr, $melting_max_c:expr],)?
         // example_1 - ignition_c: [100.0, 110.0]
         $(ignition_c:[$ignition_min:expr, $ignition_max:expr],)?
         // example_1 - burning_c: [500.0, 610.0]
         $(burning_c:[$burning_min:expr, $burning_max:expr],)?
-        // example_1 - heat_value_mj: [14.0, 15.5]
-        $(heat_value_mj:[$heat_value_min:expr, $heat_value_max:expr],)?
+        // example_1 - heat_value_mj: [hhv: [15.8, 18.0], lhv: [14.0, 16.0], basis: HeatBasis::AsReceived]
+        $(heat_value_mj:[hhv: [$hhv_min:expr, $hhv_max:expr], lhv: [$lhv_min:expr, $lhv_max:expr], basis: $heat_basis:path],)?
+        // a gas that burns, needs gas and heat_value_mj
+        // example_1 - gas_heat_value: true
+        $(gas_heat_value: $gas_heat_value:literal,)?
+        // example_1 - ultimate_pct: [c: 40.0, h: 3.0, o: 12.0, n: 0.6, s: 0.4, moisture: 35.0, ash: 9.0]
+        $(ultimate_pct:[c: $ult_c:expr, h: $ult_h:expr, o: $ult_o:expr, n: $ult_n:expr, s: $ult_s:expr, moisture: $ult_moisture:expr, ash: $ult_ash:expr],)?
+        // example_1 - flammability: [lfl: 4.0, ufl: 75.0, mie_millijoule: 0.017, autoignition_c: [500.0, 585.0]]
+        // example_2 - flammability: [lfl: 1.4, ufl: 7.6, mie_millijoule: 0.2, autoignition_c: [246.0, 280.0], flash_point_c: -43.0]
+        // example_3 - flammability: [lfl: 12.5, ufl: 74.0, autoignition_c: [605.0, 609.0]]
+        $(flammability:[lfl: $lfl:expr, ufl: $ufl:expr, $(mie_millijoule: $mie:expr,)? autoignition_c: [$autoignition_min:expr, $autoignition_max:expr] $(, flash_point_c: $flash_point:expr)?],)?
+        // example_1 - fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.6]]
+        $(fuel:[$fuel_group:path, moisture: [$fuel_moisture_min:expr, $fuel_moisture_max:expr], ash: [$fuel_ash_min:expr, $fuel_ash_max:expr]],)?
         $(metal:$metal:path,)?
+        // example_1 - precious: [PreciousMetal::Gold, 1000.0]
+        $(precious:[$precious_metal:path, $fineness:expr],)?
+        // example_1 - resistivity_nohm_m: [16.78, 17.24], tcr_k: [0.00386, 0.00404]
+        $(resistivity_nohm_m:[$resistivity_min:expr, $resistivity_max:expr], tcr_k:[$tcr_min:expr, $tcr_max:expr],)?
+        // example_1 - magnetic: [MagneticClass::Diamagnetic, -9.63e-6, -9.63e-6]
+        // example_2 - magnetic: [MagneticClass::Ferromagnetic, 200.0, 5000.0, curie_c: 770.0]
+        $(magnetic:[$magnetic_class:path, $chi_min:expr, $chi_max:expr $(, curie_c: $curie_c:expr)?],)?
+        // example_1 - appearance: [color: [1.0, 0.766, 0.336], metallic: true, roughness: 0.2, ior: 0.47, transmission: 0.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.2, 0.7]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.16, 0.18], specific_heat_jkgk: [1700.0, 2400.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+        // example_1 - elastic_gpa: [110.0, 130.0], poisson: [0.34, 0.34]
+        $(elastic_gpa:[$young_min:expr, $young_max:expr], poisson:[$poisson_min:expr, $poisson_max:expr],)?
+        // measured sound speeds, only for materials without elastic_gpa (it derives them)
+        // example_1 - sound_ms: [longitudinal: [5000.0, 6400.0], shear: [2700.0, 3400.0]]
+        $(sound_ms:[longitudinal: [$sound_l_min:expr, $sound_l_max:expr], shear: [$sound_s_min:expr, $sound_s_max:expr]],)?
     ) => {
-        new_mat!($struct_name, names:[$($field_name : $lang_literal),*]);
-        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max]);
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            [as_element, Element],
+            $([as_ignition, Ignition, $ignition_min],)?
+            $([as_burning, Burning, $burning_min],)?
+            $([as_heat_value, HeatValue, $hhv_min],)?
+            $([as_electrical, Electrical, $resistivity_min],)?
+            $([as_magnetic, Magnetic, $magnetic_class],)?
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_elastic, Elastic, $young_min],)?
+            $([as_acoustic, Acoustic, $young_min],)?
+            $([as_acoustic, Acoustic, $sound_l_min],)?
+            $([as_flammability, Flammability, $lfl],)?
+            $([as_fuel, Fuel, $fuel_group],)?
+            $([as_gas, Gas, $molar_mass],)?
+            $([as_gas_heat_value, GasHeatValue, $gas_heat_value],)?
+            $([as_precious, Precious, $precious_metal],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
         mat_add!($struct_name, $number, $symbol);
+        $(
+            mat_add!($struct_name, gas:[molar_mass_g_mol: $molar_mass, critical: [t_k: $critical_t, p_kpa: $critical_p], acentric: $acentric]);
+        )?
         $(
             mat_add!($struct_name, melting:[$melting_min_c, $melting_max_c]);
         )?
@@ -61,11 +206,44 @@ macro_rules! new_elem {
             mat_add!($struct_name, burning_c:[$burning_min, $burning_max]);
         )?
         $(
-            mat_add!($struct_name, heat_value_mj:[$heat_value_min, $heat_value_max]);
+            mat_add!($struct_name, heat_value_mj:[hhv: [$hhv_min, $hhv_max], lhv: [$lhv_min, $lhv_max], basis: $heat_basis]);
+        )?
+        $(
+            mat_add!($struct_name, ultimate_pct:[c: $ult_c, h: $ult_h, o: $ult_o, n: $ult_n, s: $ult_s, moisture: $ult_moisture, ash: $ult_ash]);
+        )?
+        $(
+            mat_add!($struct_name, flammability:[lfl: $lfl, ufl: $ufl, $(mie_millijoule: $mie,)? autoignition_c: [$autoignition_min, $autoignition_max] $(, flash_point_c: $flash_point)?]);
+        )?
+        $(
+            mat_add!($struct_name, fuel:[$fuel_group, moisture: [$fuel_moisture_min, $fuel_moisture_max], ash: [$fuel_ash_min, $fuel_ash_max]]);
         )?
         $(
             mat_add!($struct_name, metal: $metal);
         )?
+        $(
+            mat_add!($struct_name, precious:[$precious_metal, $fineness]);
+        )?
+        $(
+            mat_add!($struct_name, resistivity_nohm_m:[$resistivity_min, $resistivity_max], tcr_k:[$tcr_min, $tcr_max]);
+        )?
+        $(
+            mat_add!($struct_name, magnetic:[$magnetic_class, $chi_min, $chi_max $(, curie_c: $curie_c)?]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+        $(
+            mat_add!($struct_name, elastic_gpa:[$young_min, $young_max], poisson:[$poisson_min, $poisson_max]);
+        )?
+        $(
+            mat_add!($struct_name, sound_ms:[longitudinal: [$sound_l_min, $sound_l_max], shear: [$sound_s_min, $sound_s_max]]);
+        )?
     };
 }
 macro_rules! new_rock {
@@ -80,17 +258,58 @@ macro_rules! new_rock {
         group: [$group_trait:ident, $group:path $(, $subgroup:ident, $subgroup_val:path)?],
         // example_1 - dencity_gsm3: [1.1, 1.5]
         dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
         // example_1 - melting_c: [1450.0, 1500.0]
         $(melting_c:[$melting_min_c:expr, $melting_max_c:expr],)?
         // example_1 - ignition_c: [100.0, 110.0]
         $(ignition_c:[$ignition_min:expr, $ignition_max:expr],)?
         // example_1 - burning_c: [500.0, 610.0]
         $(burning_c:[$burning_min:expr, $burning_max:expr],)?
-        // example_1 - heat_value_mj: [14.0, 15.5]
-        $(heat_value_mj:[$heat_value_min:expr, $heat_value_max:expr],)?
+        // example_1 - heat_value_mj: [hhv: [15.8, 18.0], lhv: [14.0, 16.0], basis: HeatBasis::AsReceived]
+        $(heat_value_mj:[hhv: [$hhv_min:expr, $hhv_max:expr], lhv: [$lhv_min:expr, $lhv_max:expr], basis: $heat_basis:path],)?
+        // example_1 - ultimate_pct: [c: 40.0, h: 3.0, o: 12.0, n: 0.6, s: 0.4, moisture: 35.0, ash: 9.0]
+        $(ultimate_pct:[c: $ult_c:expr, h: $ult_h:expr, o: $ult_o:expr, n: $ult_n:expr, s: $ult_s:expr, moisture: $ult_moisture:expr, ash: $ult_ash:expr],)?
+        // example_1 - flammability: [lfl: 4.0, ufl: 75.0, mie_millijoule: 0.017, autoignition_c: [500.0, 585.0]]
+        // example_2 - flammability: [lfl: 1.4, ufl: 7.6, mie_millijoule: 0.2, autoignition_c: [246.0, 280.0], flash_point_c: -43.0]
+        // example_3 - flammability: [lfl: 12.5, ufl: 74.0, autoignition_c: [605.0, 609.0]]
+        $(flammability:[lfl: $lfl:expr, ufl: $ufl:expr, $(mie_millijoule: $mie:expr,)? autoignition_c: [$autoignition_min:expr, $autoignition_max:expr] $(, flash_point_c: $flash_point:expr)?],)?
+        // example_1 - fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.6]]
+        $(fuel:[$fuel_group:path, moisture: [$fuel_moisture_min:expr, $fuel_moisture_max:expr], ash: [$fuel_ash_min:expr, $fuel_ash_max:expr]],)?
+        // example_1 - proximate_pct: [moisture: 35.0, volatile: 28.0, fixed_carbon: 28.0, ash: 9.0, sulfur: 0.4, agglomerating: false]
+        $(proximate_pct:[moisture: $prox_moisture:expr, volatile: $prox_volatile:expr, fixed_carbon: $prox_fixed_carbon:expr, ash: $prox_ash:expr, sulfur: $prox_sulfur:expr, agglomerating: $prox_agglomerating:expr],)?
+        // example_1 - magnetic: [MagneticClass::Diamagnetic, -9.63e-6, -9.63e-6]
+        // example_2 - magnetic: [MagneticClass::Ferromagnetic, 200.0, 5000.0, curie_c: 770.0]
+        $(magnetic:[$magnetic_class:path, $chi_min:expr, $chi_max:expr $(, curie_c: $curie_c:expr)?],)?
+        // example_1 - appearance: [color: [1.0, 0.766, 0.336], metallic: true, roughness: 0.2, ior: 0.47, transmission: 0.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.2, 0.7]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.16, 0.18], specific_heat_jkgk: [1700.0, 2400.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+        // example_1 - elastic_gpa: [110.0, 130.0], poisson: [0.34, 0.34]
+        $(elastic_gpa:[$young_min:expr, $young_max:expr], poisson:[$poisson_min:expr, $poisson_max:expr],)?
+        // measured sound speeds, only for materials without elastic_gpa (it derives them)
+        // example_1 - sound_ms: [longitudinal: [5000.0, 6400.0], shear: [2700.0, 3400.0]]
+        $(sound_ms:[longitudinal: [$sound_l_min:expr, $sound_l_max:expr], shear: [$sound_s_min:expr, $sound_s_max:expr]],)?
     ) => {
-        new_mat!($struct_name, names:[$($field_name : $lang_literal),*]);
-        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max]);
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_ignition, Ignition, $ignition_min],)?
+            $([as_burning, Burning, $burning_min],)?
+            $([as_heat_value, HeatValue, $hhv_min],)?
+            $([as_magnetic, Magnetic, $magnetic_class],)?
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_elastic, Elastic, $young_min],)?
+            $([as_acoustic, Acoustic, $young_min],)?
+            $([as_acoustic, Acoustic, $sound_l_min],)?
+            $([as_flammability, Flammability, $lfl],)?
+            $([as_fuel, Fuel, $fuel_group],)?
+            $([as_seismic, Seismic, $sound_l_min],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
         mat_add!($struct_name, $group_trait, $group $(, $subgroup, $subgroup_val)?);
         $(
             mat_add!($struct_name, melting:[$melting_min_c, $melting_max_c]);
@@ -102,7 +321,38 @@ macro_rules! new_rock {
             mat_add!($struct_name, burning_c:[$burning_min, $burning_max]);
         )?
         $(
-            mat_add!($struct_name, heat_value_mj:[$heat_value_min, $heat_value_max]);
+            mat_add!($struct_name, heat_value_mj:[hhv: [$hhv_min, $hhv_max], lhv: [$lhv_min, $lhv_max], basis: $heat_basis]);
+        )?
+        $(
+            mat_add!($struct_name, ultimate_pct:[c: $ult_c, h: $ult_h, o: $ult_o, n: $ult_n, s: $ult_s, moisture: $ult_moisture, ash: $ult_ash]);
+        )?
+        $(
+            mat_add!($struct_name, flammability:[lfl: $lfl, ufl: $ufl, $(mie_millijoule: $mie,)? autoignition_c: [$autoignition_min, $autoignition_max] $(, flash_point_c: $flash_point)?]);
+        )?
+        $(
+            mat_add!($struct_name, fuel:[$fuel_group, moisture: [$fuel_moisture_min, $fuel_moisture_max], ash: [$fuel_ash_min, $fuel_ash_max]]);
+        )?
+        $(
+            mat_add!($struct_name, proximate_pct:[moisture: $prox_moisture, volatile: $prox_volatile, fixed_carbon: $prox_fixed_carbon, ash: $prox_ash, sulfur: $prox_sulfur, agglomerating: $prox_agglomerating]);
+        )?
+        $(
+            mat_add!($struct_name, magnetic:[$magnetic_class, $chi_min, $chi_max $(, curie_c: $curie_c)?]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+        $(
+            mat_add!($struct_name, elastic_gpa:[$young_min, $young_max], poisson:[$poisson_min, $poisson_max]);
+        )?
+        $(
+            mat_add!($struct_name, sound_ms:[longitudinal: [$sound_l_min, $sound_l_max], shear: [$sound_s_min, $sound_s_max]]);
+            mat_add!($struct_name, seismic:[vp: [$sound_l_min, $sound_l_max], vs: [$sound_s_min, $sound_s_max]]);
         )?
     };
 }
@@ -116,12 +366,67 @@ macro_rules! new_alloy {
         chemical_composition: [$([$element:ident, $element_min:expr, $element_max:expr],)*],
         // example_1 - dencity_gsm3: [1.1, 1.5]
         dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
         // example_1 - melting_c: [1450.0, 1500.0]
         melting_c:[$melting_min_c:expr, $melting_max_c:expr],
+        // example_1 - precious: [PreciousMetal::Gold, 750.0]
+        $(precious:[$precious_metal:path, $fineness:expr],)?
+        // example_1 - resistivity_nohm_m: [60.0, 80.0], tcr_k: [0.0015, 0.0020]
+        $(resistivity_nohm_m:[$resistivity_min:expr, $resistivity_max:expr], tcr_k:[$tcr_min:expr, $tcr_max:expr],)?
+        // example_1 - magnetic: [MagneticClass::Diamagnetic, -9.63e-6, -9.63e-6]
+        // example_2 - magnetic: [MagneticClass::Ferromagnetic, 200.0, 5000.0, curie_c: 770.0]
+        $(magnetic:[$magnetic_class:path, $chi_min:expr, $chi_max:expr $(, curie_c: $curie_c:expr)?],)?
+        // example_1 - appearance: [color: [1.0, 0.766, 0.336], metallic: true, roughness: 0.2, ior: 0.47, transmission: 0.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.2, 0.7]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.16, 0.18], specific_heat_jkgk: [1700.0, 2400.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+        // example_1 - elastic_gpa: [110.0, 130.0], poisson: [0.34, 0.34]
+        $(elastic_gpa:[$young_min:expr, $young_max:expr], poisson:[$poisson_min:expr, $poisson_max:expr],)?
+        // measured sound speeds, only for materials without elastic_gpa (it derives them)
+        // example_1 - sound_ms: [longitudinal: [5000.0, 6400.0], shear: [2700.0, 3400.0]]
+        $(sound_ms:[longitudinal: [$sound_l_min:expr, $sound_l_max:expr], shear: [$sound_s_min:expr, $sound_s_max:expr]],)?
     ) => {
-        new_mat!($struct_name, names:[$($field_name : $lang_literal),*]);
-        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max]);
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_electrical, Electrical, $resistivity_min],)?
+            $([as_magnetic, Magnetic, $magnetic_class],)?
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_elastic, Elastic, $young_min],)?
+            $([as_acoustic, Acoustic, $young_min],)?
+            $([as_acoustic, Acoustic, $sound_l_min],)?
+            $([as_precious, Precious, $precious_metal],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
         mat_add!($struct_name, melting:[$melting_min_c, $melting_max_c]);
+        $(
+            mat_add!($struct_name, precious:[$precious_metal, $fineness]);
+        )?
+        $(
+            mat_add!($struct_name, resistivity_nohm_m:[$resistivity_min, $resistivity_max], tcr_k:[$tcr_min, $tcr_max]);
+        )?
+        $(
+            mat_add!($struct_name, magnetic:[$magnetic_class, $chi_min, $chi_max $(, curie_c: $curie_c)?]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+        $(
+            mat_add!($struct_name, elastic_gpa:[$young_min, $young_max], poisson:[$poisson_min, $poisson_max]);
+        )?
+        $(
+            mat_add!($struct_name, sound_ms:[longitudinal: [$sound_l_min, $sound_l_max], shear: [$sound_s_min, $sound_s_max]]);
+        )?
         mat_add!($struct_name, alloy: {
             chemical_composition: [
                 $(
@@ -131,9 +436,311 @@ macro_rules! new_alloy {
         });
     };
 }
+macro_rules! new_fuel {
+    (
+        // example_1 - Oak
+        $struct_name:ident,
+        // example_1 - names: [RU:"Дуб", EN:"Oak"]
+        // example_2 - names: [EN:"Oak"]
+        names: [$($field_name:path : $lang_literal:literal),*],
+        // example_1 - fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.6]]
+        fuel: [$fuel_group:path, moisture: [$fuel_moisture_min:expr, $fuel_moisture_max:expr], ash: [$fuel_ash_min:expr, $fuel_ash_max:expr]],
+        // example_1 - dencity_gsm3: [0.65, 0.85]
+        dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
+        // example_1 - gas: [molar_mass_g_mol: 2.016, critical: [t_k: 33.19, p_kpa: 1296.4], acentric: -0.216]
+        $(gas:[molar_mass_g_mol: $molar_mass:expr, critical: [t_k: $critical_t:expr, p_kpa: $critical_p:expr], acentric: $acentric:expr],)?
+        // example_1 - ignition_c: [100.0, 110.0]
+        $(ignition_c:[$ignition_min:expr, $ignition_max:expr],)?
+        // example_1 - burning_c: [500.0, 610.0]
+        $(burning_c:[$burning_min:expr, $burning_max:expr],)?
+        // example_1 - heat_value_mj: [hhv: [19.5, 20.0], lhv: [18.2, 18.7], basis: HeatBasis::DryAshFree]
+        heat_value_mj: [hhv: [$hhv_min:expr, $hhv_max:expr], lhv: [$lhv_min:expr, $lhv_max:expr], basis: $heat_basis:path],
+        // example_1 - ultimate_pct: [c: 50.5, h: 6.0, o: 43.1, n: 0.3, s: 0.1, moisture: 0.0, ash: 0.0]
+        ultimate_pct: [c: $ult_c:expr, h: $ult_h:expr, o: $ult_o:expr, n: $ult_n:expr, s: $ult_s:expr, moisture: $ult_moisture:expr, ash: $ult_ash:expr],
+        // example_1 - flammability: [lfl: 1.4, ufl: 7.6, mie_millijoule: 0.2, autoignition_c: [246.0, 280.0], flash_point_c: -43.0]
+        $(flammability:[lfl: $lfl:expr, ufl: $ufl:expr, $(mie_millijoule: $mie:expr,)? autoignition_c: [$autoignition_min:expr, $autoignition_max:expr] $(, flash_point_c: $flash_point:expr)?],)?
+        // example_1 - appearance: [color: [1.0, 0.766, 0.336], metallic: true, roughness: 0.2, ior: 0.47, transmission: 0.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.2, 0.7]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.16, 0.18], specific_heat_jkgk: [1700.0, 2400.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+        // example_1 - boiling_c: [100.0, 100.0]
+        $(boiling_c:[$boiling_min_c:expr, $boiling_max_c:expr],)?
+        // example_1 - liquid: [viscosity_vogel: [a_pa_s: 2.414e-5, b_k: 247.8, c_k: 140.0], surface_tension_mn_m: [72.8, at_c: 20.0, per_k: -0.168]]
+        $(liquid:[viscosity_vogel: [a_pa_s: $vogel_a:expr, b_k: $vogel_b:expr, c_k: $vogel_c:expr], surface_tension_mn_m: [$tension:expr, at_c: $tension_at:expr, per_k: $tension_slope:expr]],)?
+        // example_1 - antoine_mmhg_c: [8.07131, 1730.63, 233.426]
+        $(antoine_mmhg_c:[$antoine_a:expr, $antoine_b:expr, $antoine_c:expr],)?
+    ) => {
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_ignition, Ignition, $ignition_min],)?
+            $([as_burning, Burning, $burning_min],)?
+            [as_heat_value, HeatValue],
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_flammability, Flammability, $lfl],)?
+            [as_fuel, Fuel],
+            $([as_boiling, Boiling, $boiling_min_c],)?
+            $([as_liquid, Liquid, $vogel_a],)?
+            $([as_vapour_pressure, VapourPressure, $antoine_a],)?
+            $([as_gas, Gas, $molar_mass],)?
+            $([as_gas_heat_value, GasHeatValue, $molar_mass],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
+        mat_add!($struct_name, fuel:[$fuel_group, moisture: [$fuel_moisture_min, $fuel_moisture_max], ash: [$fuel_ash_min, $fuel_ash_max]]);
+        mat_add!($struct_name, heat_value_mj:[hhv: [$hhv_min, $hhv_max], lhv: [$lhv_min, $lhv_max], basis: $heat_basis]);
+        mat_add!($struct_name, ultimate_pct:[c: $ult_c, h: $ult_h, o: $ult_o, n: $ult_n, s: $ult_s, moisture: $ult_moisture, ash: $ult_ash]);
+        $(
+            mat_add!($struct_name, gas:[molar_mass_g_mol: $molar_mass, critical: [t_k: $critical_t, p_kpa: $critical_p], acentric: $acentric]);
+        )?
+        $(
+            mat_add!($struct_name, ignition_c:[$ignition_min, $ignition_max]);
+        )?
+        $(
+            mat_add!($struct_name, burning_c:[$burning_min, $burning_max]);
+        )?
+        $(
+            mat_add!($struct_name, flammability:[lfl: $lfl, ufl: $ufl, $(mie_millijoule: $mie,)? autoignition_c: [$autoignition_min, $autoignition_max] $(, flash_point_c: $flash_point)?]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+        $(
+            mat_add!($struct_name, boiling:[$boiling_min_c, $boiling_max_c]);
+        )?
+        $(
+            mat_add!($struct_name, liquid:[viscosity_vogel: [a_pa_s: $vogel_a, b_k: $vogel_b, c_k: $vogel_c], surface_tension_mn_m: [$tension, at_c: $tension_at, per_k: $tension_slope]]);
+        )?
+        $(
+            mat_add!($struct_name, antoine_mmhg_c:[$antoine_a, $antoine_b, $antoine_c]);
+        )?
+    };
+}
+macro_rules! new_liquid {
+    (
+        // example_1 - Water
+        $struct_name:ident,
+        // example_1 - names: [RU:"Вода", EN:"Water"]
+        // example_2 - names: [EN:"Water"]
+        names: [$($field_name:path : $lang_literal:literal),*],
+        // example_1 - dencity_gsm3: [0.9982, 0.9982]
+        dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
+        // freezing point
+        // example_1 - melting_c: [0.0, 0.0]
+        $(melting_c:[$melting_min_c:expr, $melting_max_c:expr],)?
+        // example_1 - boiling_c: [100.0, 100.0]
+        $(boiling_c:[$boiling_min_c:expr, $boiling_max_c:expr],)?
+        // example_1 - liquid: [viscosity_vogel: [a_pa_s: 2.414e-5, b_k: 247.8, c_k: 140.0], surface_tension_mn_m: [72.8, at_c: 20.0, per_k: -0.168]]
+        liquid: [viscosity_vogel: [a_pa_s: $vogel_a:expr, b_k: $vogel_b:expr, c_k: $vogel_c:expr], surface_tension_mn_m: [$tension:expr, at_c: $tension_at:expr, per_k: $tension_slope:expr]],
+        // example_1 - antoine_mmhg_c: [8.07131, 1730.63, 233.426]
+        $(antoine_mmhg_c:[$antoine_a:expr, $antoine_b:expr, $antoine_c:expr],)?
+        // example_1 - latent_heat_kj_kg: [fusion: 333.55, vaporization: 2256.4]
+        $(latent_heat_kj_kg:[fusion: $fusion:expr, vaporization: $vaporization:expr],)?
+        // example_1 - appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.333, transmission: 1.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.95, 0.96]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.598, 0.598], specific_heat_jkgk: [4182.0, 4182.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+    ) => {
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_boiling, Boiling, $boiling_min_c],)?
+            [as_liquid, Liquid],
+            $([as_vapour_pressure, VapourPressure, $antoine_a],)?
+            $([as_latent_heat, LatentHeat, $fusion],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
+        mat_add!($struct_name, liquid:[viscosity_vogel: [a_pa_s: $vogel_a, b_k: $vogel_b, c_k: $vogel_c], surface_tension_mn_m: [$tension, at_c: $tension_at, per_k: $tension_slope]]);
+        $(
+            mat_add!($struct_name, melting:[$melting_min_c, $melting_max_c]);
+        )?
+        $(
+            mat_add!($struct_name, boiling:[$boiling_min_c, $boiling_max_c]);
+        )?
+        $(
+            mat_add!($struct_name, antoine_mmhg_c:[$antoine_a, $antoine_b, $antoine_c]);
+        )?
+        $(
+            mat_add!($struct_name, latent_heat_kj_kg:[fusion: $fusion, vaporization: $vaporization]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+    };
+}
+macro_rules! new_compound {
+    (
+        // example_1 - CarbonDioxide
+        $struct_name:ident,
+        // example_1 - names: [RU:"Углекислый газ", EN:"Carbon Dioxide"]
+        // example_2 - names: [EN:"Carbon Dioxide"]
+        names: [$($field_name:path : $lang_literal:literal),*],
+        // example_1 - formula: "CO2"
+        formula: $formula:literal,
+        // example_1 - dencity_gsm3: [0.0019768, 0.0019768]
+        dencity_gsm3: [$density_min:expr, $density_max:expr],
+        // example_1 - dencity_at: ReferenceConditions::STP, default - ReferenceConditions::ROOM
+        $(dencity_at: $density_at:expr,)?
+        // example_1 - gas: [molar_mass_g_mol: 44.009, critical: [t_k: 304.13, p_kpa: 7377.3], acentric: 0.224]
+        $(gas:[molar_mass_g_mol: $molar_mass:expr, critical: [t_k: $critical_t:expr, p_kpa: $critical_p:expr], acentric: $acentric:expr],)?
+        // example_1 - melting_c: [0.0, 0.0]
+        $(melting_c:[$melting_min_c:expr, $melting_max_c:expr],)?
+        // example_1 - latent_heat_kj_kg: [fusion: 333.55, vaporization: 2256.4]
+        // example_2 - latent_heat_kj_kg: [fusion: 333.55, sublimation: 2834.0]
+        $(latent_heat_kj_kg:[fusion: $fusion:expr, $phase_change:ident: $latent_heat:expr],)?
+        // example_1 - appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00045, transmission: 1.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.2, 0.7]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // example_1 - thermal: [conductivity_wmk: [0.0146, 0.0166], specific_heat_jkgk: [820.0, 850.0]]
+        $(thermal:[conductivity_wmk: [$thermal_k_min:expr, $thermal_k_max:expr], specific_heat_jkgk: [$thermal_c_min:expr, $thermal_c_max:expr]],)?
+    ) => {
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_thermal, Thermal, $thermal_k_min],)?
+            $([as_gas, Gas, $molar_mass],)?
+            $([as_latent_heat, LatentHeat, $fusion],)?
+        ]);
+        mat_add!($struct_name, dencity_gsm3:[$density_min, $density_max] $(, at: $density_at)?);
+        mat_add!($struct_name, formula: $formula);
+        $(
+            mat_add!($struct_name, gas:[molar_mass_g_mol: $molar_mass, critical: [t_k: $critical_t, p_kpa: $critical_p], acentric: $acentric]);
+        )?
+        $(
+            mat_add!($struct_name, melting:[$melting_min_c, $melting_max_c]);
+        )?
+        $(
+            mat_add!($struct_name, latent_heat_kj_kg:[fusion: $fusion, $phase_change: $latent_heat]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, thermal:[conductivity_wmk: [$thermal_k_min, $thermal_k_max], specific_heat_jkgk: [$thermal_c_min, $thermal_c_max]]);
+        )?
+    };
+}
+macro_rules! new_composite {
+    (
+        // example_1 - Concrete
+        $struct_name:ident,
+        // example_1 - names: [RU:"Бетон", EN:"Concrete"]
+        // example_2 - names: [EN:"Concrete"]
+        names: [$($field_name:path : $lang_literal:literal),*],
+        // % of the solid volume, adding up to 100
+        // example_1 - volume_pct: [[Granite, 45.0], [Quartz, 30.0], [CementStone, 25.0]]
+        volume_pct: [$([$constituent:ident, $constituent_pct:expr]),* $(,)?],
+        // example_1 - porosity_pct: 10.0
+        porosity_pct: $porosity:expr,
+        // fluid of the saturated pores and the share of the pores it fills in the natural state
+        // example_1 - pores: [Water, saturation_pct: 50.0]
+        pores: [$pore_fluid:ident, saturation_pct: $saturation:expr],
+        // composite rocks, e.g. sandstone
+        // example_1 - group: [Sedimentary, GroupRock::Sedimentary, SubgroupSedimentary, SubgroupSedimentary::Clastic]
+        $(group: [$group_trait:ident, $group:path $(, $subgroup:ident, $subgroup_val:path)?],)?
+        // example_1 - appearance: [color: [0.6, 0.6, 0.58], metallic: false, roughness: 0.9, ior: 1.5, transmission: 0.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+        // example_1 - emissivity: [0.85, 0.95]
+        $(emissivity:[$emissivity_min:expr, $emissivity_max:expr],)?
+        // needs group, P- and S-wave velocities of the rock
+        // example_1 - sound_ms: [longitudinal: [2000.0, 4500.0], shear: [1200.0, 2800.0]]
+        $(sound_ms:[longitudinal: [$sound_l_min:expr, $sound_l_max:expr], shear: [$sound_s_min:expr, $sound_s_max:expr]],)?
+    ) => {
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            [as_thermal, Thermal],
+            [as_composite, Composite],
+            $([as_appearance, Appearance, $color],)?
+            $([as_emissivity, Emissivity, $emissivity_min],)?
+            $([as_acoustic, Acoustic, $sound_l_min],)?
+            $([as_seismic, Seismic, $sound_l_min],)?
+        ]);
+        mat_add!($struct_name, volume_pct: [$([$constituent, $constituent_pct]),*], porosity_pct: $porosity, pores: [$pore_fluid, saturation_pct: $saturation]);
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+        $(
+            mat_add!($struct_name, emissivity:[$emissivity_min, $emissivity_max]);
+        )?
+        $(
+            mat_add!($struct_name, $group_trait, $group $(, $subgroup, $subgroup_val)?);
+        )?
+        $(
+            mat_add!($struct_name, sound_ms:[longitudinal: [$sound_l_min, $sound_l_max], shear: [$sound_s_min, $sound_s_max]]);
+            mat_add!($struct_name, seismic:[vp: [$sound_l_min, $sound_l_max], vs: [$sound_s_min, $sound_s_max]]);
+        )?
+    };
+}
+macro_rules! new_mixture {
+    (
+        // example_1 - Air
+        $struct_name:ident,
+        // example_1 - names: [RU:"Воздух", EN:"Air"]
+        // example_2 - names: [EN:"Air"]
+        names: [$($field_name:path : $lang_literal:literal),*],
+        // example_1 - mole_pct: [[Nitrogen, 78.084], [Oxygen, 20.946], [Argon, 0.934], [CarbonDioxide, 0.036]]
+        mole_pct: [$([$component:ident, $component_pct:expr]),* $(,)?],
+        // heating value is the mass-weighted average of the components
+        // example_1 - heat_value: HeatBasis::AsReceived
+        $(heat_value: $heat_basis:path,)?
+        // limits are derived from the components, the autoignition temperature is not
+        // example_1 - flammability: [autoignition_c: [580.0, 640.0]]
+        $(flammability:[autoignition_c: [$autoignition_min:expr, $autoignition_max:expr]],)?
+        // example_1 - appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000293, transmission: 1.0]
+        $(appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr],)?
+    ) => {
+        new_mat!($struct_name, names:[$($field_name : $lang_literal),*], dyn:[
+            [as_density, Density],
+            $([as_heat_value, HeatValue, $heat_basis],)?
+            $([as_gas_heat_value, GasHeatValue, $heat_basis],)?
+            $([as_flammability, Flammability, $autoignition_min],)?
+            $([as_appearance, Appearance, $color],)?
+            [as_gas, Gas],
+            [as_gas_mixture, GasMixture],
+        ]);
+        mat_add!($struct_name, mole_pct: [$([$component, $component_pct]),*]);
+        $(
+            mat_add!($struct_name, mixture_heat_value: $heat_basis);
+        )?
+        $(
+            mat_add!($struct_name, mixture_flammability:[autoignition_c: [$autoignition_min, $autoignition_max]]);
+        )?
+        $(
+            mat_add!($struct_name, appearance:[color: $color, metallic: $metallic, roughness: $roughness, ior: $ior, transmission: $transmission]);
+        )?
+    };
+}
 macro_rules! new_mat {
     // new material with names
-    ( $struct_name:ident, names: [$($field_name:path : $lang_literal:literal),*]) => {
+    // dyn: [[as_magnetic, Magnetic, ...],] - `Material` accessors to other traits, tokens after the trait are ignored
+    ( $struct_name:ident, names: [$($field_name:path : $lang_literal:literal),*], dyn: [$([$as_fn:ident, $as_trait:ident $($_ignore:tt)*],)*]) => {
         pub struct $struct_name;
         
         impl Material for $struct_name {
@@ -149,6 +756,11 @@ macro_rules! new_mat {
                     _ => "",
                 }
             }
+            $(
+                fn $as_fn(&self) -> Option<&dyn $as_trait> {
+                    Some(self)
+                }
+            )*
         }
     };
 
@@ -165,9 +777,74 @@ macro_rules! mat_add {
             }
         }
     };
-    // Add Density for material
+    // Add Boiling for material
+    ($struct_name:ident, boiling:[$min_c:expr, $max_c:expr]) => {
+        impl Boiling for $struct_name {
+            fn get_boiling_avg_c(&self) -> Celsius {
+                ($min_c + $max_c) * 0.5
+            }
+            fn get_boiling_avg_k(&self) -> Kelvin {
+                (($min_c + $max_c) * 0.5) + 273.15
+            }
+        }
+    };
+    // Add Liquid for material
+    ($struct_name:ident, liquid:[viscosity_vogel: [a_pa_s: $a:expr, b_k: $b:expr, c_k: $c:expr], surface_tension_mn_m: [$tension:expr, at_c: $tension_at:expr, per_k: $tension_slope:expr]]) => {
+        impl Liquid for $struct_name {
+            fn get_viscosity_vogel(&self) -> [f32; 3] {
+                [$a, $b, $c]
+            }
+            fn get_surface_tension_n_m(&self, t: Kelvin) -> N_m {
+                (($tension) + ($tension_slope) * (t - 273.15 - ($tension_at))).max(0.0) * 0.001
+            }
+        }
+    };
+    // Add VapourPressure for material
+    ($struct_name:ident, antoine_mmhg_c:[$a:expr, $b:expr, $c:expr]) => {
+        impl VapourPressure for $struct_name {
+            fn get_antoine_mmhg_c(&self) -> [f32; 3] {
+                [$a, $b, $c]
+            }
+        }
+    };
+    // Add Precious for material
+    ($struct_name:ident, precious:[$precious_metal:path, $fineness:expr]) => {
+        impl Precious for $struct_name {
+            fn get_precious_metal(&self) -> PreciousMetal {$precious_metal}
+            fn get_fineness(&self) -> Fineness {Fineness {millesimal: $fineness}}
+        }
+    };
+    // Add LatentHeat for material
+    ($struct_name:ident, latent_heat_kj_kg:[fusion: $fusion:expr, vaporization: $vaporization:expr]) => {
+        impl LatentHeat for $struct_name {
+            fn get_fusion_kj_kg(&self) -> KJ_kg {$fusion}
+            fn get_vaporization_kj_kg(&self) -> Option<KJ_kg> {Some($vaporization)}
+        }
+    };
+    // Add LatentHeat for a solid phase, it turns into vapour by sublimation
+    ($struct_name:ident, latent_heat_kj_kg:[fusion: $fusion:expr, sublimation: $sublimation:expr]) => {
+        impl LatentHeat for $struct_name {
+            fn get_fusion_kj_kg(&self) -> KJ_kg {$fusion}
+            fn get_vaporization_kj_kg(&self) -> Option<KJ_kg> {None}
+            fn get_sublimation_kj_kg(&self) -> Option<KJ_kg> {Some($sublimation)}
+        }
+    };
+    // Add Density for material at room conditions
     ($struct_name:ident, dencity_gsm3:[$min_gsm3:expr, $max_gsm3:expr]) => {
+        mat_add!($struct_name, dencity_gsm3:[$min_gsm3, $max_gsm3], at: ReferenceConditions::ROOM);
+    };
+    // Add Density for material
+    ($struct_name:ident, dencity_gsm3:[$min_gsm3:expr, $max_gsm3:expr], at: $conditions:expr) => {
         impl Density for $struct_name {
+            fn get_density_conditions(&self) -> ReferenceConditions {
+                $conditions
+            }
+            fn get_density_min_gcm3(&self) -> Gsm3 {
+                $min_gsm3
+            }
+            fn get_density_max_gcm3(&self) -> Gsm3 {
+                $max_gsm3
+            }
             fn get_density_avg_gcm3(&self) -> Gsm3 {
                 ($min_gsm3 + $max_gsm3) * 0.5
             }
@@ -176,6 +853,131 @@ macro_rules! mat_add {
             }
         }
     };
+    // Add Gas for material
+    ($struct_name:ident, gas:[molar_mass_g_mol: $molar_mass:expr, critical: [t_k: $critical_t:expr, p_kpa: $critical_p:expr], acentric: $acentric:expr]) => {
+        impl Gas for $struct_name {
+            fn get_molar_mass_g_mol(&self) -> GMol {
+                $molar_mass
+            }
+            fn get_critical_temperature_k(&self) -> Kelvin {
+                $critical_t
+            }
+            fn get_critical_pressure_kpa(&self) -> KPa {
+                $critical_p
+            }
+            fn get_acentric_factor(&self) -> f32 {
+                $acentric
+            }
+        }
+    };
+    // Add Compound for material
+    ($struct_name:ident, formula: $formula:literal) => {
+        impl Compound for $struct_name {
+            fn get_formula(&self) -> &'static str {$formula}
+        }
+    };
+    // Add GasMixture, Gas (Kay's rule) and Density at STP for a mixture of gases
+    ($struct_name:ident, mole_pct: [$([$component:ident, $component_pct:expr]),*]) => {
+        impl GasMixture for $struct_name {
+            fn get_mole_composition(&self) -> &'static [(&'static dyn Material, Percent)] {
+                &[$((&$component, $component_pct)),*]
+            }
+        }
+        impl Gas for $struct_name {
+            fn get_molar_mass_g_mol(&self) -> GMol {
+                self.get_mole_average(&|gas| gas.get_molar_mass_g_mol())
+            }
+            fn get_critical_temperature_k(&self) -> Kelvin {
+                self.get_mole_average(&|gas| gas.get_critical_temperature_k())
+            }
+            fn get_critical_pressure_kpa(&self) -> KPa {
+                self.get_mole_average(&|gas| gas.get_critical_pressure_kpa())
+            }
+            fn get_acentric_factor(&self) -> f32 {
+                self.get_mole_average(&|gas| gas.get_acentric_factor())
+            }
+        }
+        impl Density for $struct_name {
+            fn get_density_conditions(&self) -> ReferenceConditions {
+                ReferenceConditions::STP
+            }
+            fn get_density_min_gcm3(&self) -> Gsm3 {
+                self.get_density_avg_gcm3()
+            }
+            fn get_density_max_gcm3(&self) -> Gsm3 {
+                self.get_density_avg_gcm3()
+            }
+            fn get_density_avg_gcm3(&self) -> Gsm3 {
+                self.get_density_avg_kgm3() * 0.001
+            }
+            fn get_density_avg_kgm3(&self) -> KGm3 {
+                let stp = ReferenceConditions::STP;
+                self.get_density_kgm3_at_tp(stp.get_temperature_k(), stp.pressure)
+            }
+        }
+    };
+    // Add Composite, Density (dry..saturated) and Thermal derived from the constituents
+    ($struct_name:ident, volume_pct: [$([$constituent:ident, $constituent_pct:expr]),*], porosity_pct: $porosity:expr, pores: [$pore_fluid:ident, saturation_pct: $saturation:expr]) => {
+        impl Composite for $struct_name {
+            fn get_constituents(&self) -> &'static [(&'static dyn Thermal, Percent)] {
+                &[$((&$constituent, $constituent_pct)),*]
+            }
+            fn get_porosity_pct(&self) -> Percent {$porosity}
+            fn get_pore_fluid(&self) -> &'static dyn Thermal {&$pore_fluid}
+            fn get_saturation_pct(&self) -> Percent {$saturation}
+        }
+        impl Density for $struct_name {
+            fn get_density_min_gcm3(&self) -> Gsm3 {
+                self.get_dry_density_kgm3() * 0.001
+            }
+            fn get_density_max_gcm3(&self) -> Gsm3 {
+                self.get_saturated_density_kgm3() * 0.001
+            }
+            fn get_density_avg_gcm3(&self) -> Gsm3 {
+                self.get_density_avg_kgm3() * 0.001
+            }
+            fn get_density_avg_kgm3(&self) -> KGm3 {
+                self.get_bulk_density_kgm3(self.get_saturation_pct())
+            }
+        }
+        impl Thermal for $struct_name {
+            fn get_thermal_conductivity_avg_wmk(&self) -> WmK {
+                self.get_effective_conductivity_wmk(self.get_saturation_pct())
+            }
+            fn get_specific_heat_avg_jkgk(&self) -> JkgK {
+                self.get_specific_heat_jkgk(self.get_saturation_pct())
+            }
+        }
+    };
+    // Add HeatValue of a mixture, mass-weighted over the components
+    ($struct_name:ident, mixture_heat_value: $heat_basis:path) => {
+        impl HeatValue for $struct_name {
+            fn get_hhv_avg_mj(&self) -> MJ_kg {
+                self.get_mass_average(&|component| component.as_heat_value().map_or(0.0, |heat| heat.get_hhv_avg_mj()))
+            }
+            fn get_lhv_avg_mj(&self) -> MJ_kg {
+                self.get_mass_average(&|component| component.as_heat_value().map_or(0.0, |heat| heat.get_lhv_avg_mj()))
+            }
+            fn get_heat_value_basis(&self) -> HeatBasis {
+                $heat_basis
+            }
+        }
+    };
+    // Add Flammability of a mixture, limits by Le Chatelier's rule.
+    // Without combustible components the range is empty: LFL 100%, UFL 0%
+    ($struct_name:ident, mixture_flammability:[autoignition_c: [$min_c:expr, $max_c:expr]]) => {
+        impl Flammability for $struct_name {
+            fn get_lfl_pct(&self) -> Percent {
+                self.get_le_chatelier_pct(&|component| component.get_lfl_pct()).unwrap_or(100.0)
+            }
+            fn get_ufl_pct(&self) -> Percent {
+                self.get_le_chatelier_pct(&|component| component.get_ufl_pct()).unwrap_or(0.0)
+            }
+            fn get_autoignition_avg_c(&self) -> Celsius {
+                ($min_c + $max_c) * 0.5
+            }
+        }
+    };
     // Add Rock for material with subgroup
     ($struct_name:ident, $group_trait:ident, $group:path, $subgroup:ident, $subgroup_val:path) => {
         impl Rock for $struct_name {
@@ -214,22 +1016,204 @@ macro_rules! mat_add {
             fn get_burning_avg_c(&self) -> Celsius {
                 ($min_c + $max_c) * 0.5
             }
-            fn get_burning_avg_k(&self) -> Kelvin {
-                (($min_c + $max_c) * 0.5) + 273.15
+            fn get_burning_avg_k(&self) -> Kelvin {
+                (($min_c + $max_c) * 0.5) + 273.15
+            }
+        }
+    };
+    // Add HeatValue for material
+    ($struct_name:ident, heat_value_mj:[hhv: [$min_hhv:expr, $max_hhv:expr], lhv: [$min_lhv:expr, $max_lhv:expr], basis: $basis:path]) => {
+        impl HeatValue for $struct_name {
+            fn get_hhv_avg_mj(&self) -> MJ_kg {
+                ($min_hhv + $max_hhv) * 0.5
+            }
+            fn get_lhv_avg_mj(&self) -> MJ_kg {
+                ($min_lhv + $max_lhv) * 0.5
+            }
+            fn get_heat_value_basis(&self) -> HeatBasis {
+                $basis
+            }
+        }
+    };
+    // Add UltimateAnalysis for material
+    ($struct_name:ident, ultimate_pct:[c: $c:expr, h: $h:expr, o: $o:expr, n: $n:expr, s: $s:expr, moisture: $moisture:expr, ash: $ash:expr]) => {
+        impl UltimateAnalysis for $struct_name {
+            fn get_ultimate_analysis(&self) -> FuelComposition {
+                FuelComposition {
+                    carbon: $c,
+                    hydrogen: $h,
+                    oxygen: $o,
+                    nitrogen: $n,
+                    sulfur: $s,
+                    moisture: $moisture,
+                    ash: $ash,
+                }
+            }
+        }
+    };
+    // Add Flammability for material
+    ($struct_name:ident, flammability:[lfl: $lfl:expr, ufl: $ufl:expr, $(mie_millijoule: $mie:expr,)? autoignition_c: [$min_c:expr, $max_c:expr] $(, flash_point_c: $flash_point:expr)?]) => {
+        impl Flammability for $struct_name {
+            fn get_lfl_pct(&self) -> Percent {$lfl}
+            fn get_ufl_pct(&self) -> Percent {$ufl}
+            $(
+                fn get_min_ignition_energy_millijoule(&self) -> Option<MilliJoule> {Some($mie)}
+            )?
+            fn get_autoignition_avg_c(&self) -> Celsius {
+                ($min_c + $max_c) * 0.5
+            }
+            $(
+                fn get_flash_point_c(&self) -> Option<Celsius> {Some($flash_point)}
+            )?
+        }
+    };
+    // Add Fuel for material
+    ($struct_name:ident, fuel:[$group:path, moisture: [$min_moisture:expr, $max_moisture:expr], ash: [$min_ash:expr, $max_ash:expr]]) => {
+        impl Fuel for $struct_name {
+            fn get_fuel_group(&self) -> GroupFuel {
+                $group
+            }
+            fn get_moisture_typical_pct(&self) -> Percent {
+                ($min_moisture + $max_moisture) * 0.5
+            }
+            fn get_ash_typical_pct(&self) -> Percent {
+                ($min_ash + $max_ash) * 0.5
+            }
+        }
+    };
+    // Add CoalAnalysis for material
+    ($struct_name:ident, proximate_pct:[moisture: $moisture:expr, volatile: $volatile:expr, fixed_carbon: $fixed_carbon:expr, ash: $ash:expr, sulfur: $sulfur:expr, agglomerating: $agglomerating:expr]) => {
+        impl CoalAnalysis for $struct_name {
+            fn get_proximate_analysis(&self) -> ProximateAnalysis {
+                ProximateAnalysis {
+                    moisture: $moisture,
+                    volatile_matter: $volatile,
+                    fixed_carbon: $fixed_carbon,
+                    ash: $ash,
+                    sulfur: $sulfur,
+                    hhv: self.get_hhv_mj_at($moisture, $ash),
+                }
+            }
+            fn is_agglomerating(&self) -> bool {
+                $agglomerating
+            }
+        }
+    };
+    // Add Electrical for material
+    ($struct_name:ident, resistivity_nohm_m:[$min_nohm:expr, $max_nohm:expr], tcr_k:[$min_tcr:expr, $max_tcr:expr]) => {
+        impl Electrical for $struct_name {
+            fn get_resistivity_avg_nohm_m(&self) -> NOhmM {
+                ($min_nohm + $max_nohm) * 0.5
+            }
+            fn get_tcr_avg_k(&self) -> PerKelvin {
+                ($min_tcr + $max_tcr) * 0.5
+            }
+        }
+    };
+    // Add Magnetic for material
+    ($struct_name:ident, magnetic:[$class:path, $min_chi:expr, $max_chi:expr]) => {
+        impl Magnetic for $struct_name {
+            fn get_magnetic_class(&self) -> MagneticClass {
+                $class
+            }
+            fn get_susceptibility_avg_si(&self) -> f32 {
+                ($min_chi + $max_chi) * 0.5
+            }
+        }
+    };
+    // Add Magnetic for material with Curie temperature
+    ($struct_name:ident, magnetic:[$class:path, $min_chi:expr, $max_chi:expr, curie_c: $curie_c:expr]) => {
+        impl Magnetic for $struct_name {
+            fn get_magnetic_class(&self) -> MagneticClass {
+                $class
+            }
+            fn get_susceptibility_avg_si(&self) -> f32 {
+                ($min_chi + $max_chi) * 0.5
+            }
+            fn get_curie_c(&self) -> Option<Celsius> {
+                Some($curie_c)
+            }
+        }
+    };
+    // Add Appearance for material
+    ($struct_name:ident, appearance:[color: $color:expr, metallic: $metallic:expr, roughness: $roughness:expr, ior: $ior:expr, transmission: $transmission:expr]) => {
+        impl Appearance for $struct_name {
+            fn get_base_color_linear(&self) -> [f32; 3] {
+                $color
+            }
+            fn is_metallic(&self) -> bool {
+                $metallic
+            }
+            fn get_roughness(&self) -> f32 {
+                $roughness
+            }
+            fn get_ior(&self) -> f32 {
+                $ior
+            }
+            fn get_transmission(&self) -> f32 {
+                $transmission
+            }
+        }
+    };
+    // Add Emissivity for material
+    ($struct_name:ident, emissivity:[$min:expr, $max:expr]) => {
+        impl Emissivity for $struct_name {
+            fn get_emissivity_avg(&self) -> f32 {
+                ($min + $max) * 0.5
+            }
+        }
+    };
+    // Add Thermal for material
+    ($struct_name:ident, thermal:[conductivity_wmk: [$k_min:expr, $k_max:expr], specific_heat_jkgk: [$c_min:expr, $c_max:expr]]) => {
+        impl Thermal for $struct_name {
+            fn get_thermal_conductivity_avg_wmk(&self) -> WmK {
+                ($k_min + $k_max) * 0.5
+            }
+            fn get_specific_heat_avg_jkgk(&self) -> JkgK {
+                ($c_min + $c_max) * 0.5
+            }
+        }
+    };
+    // Add Elastic and Acoustic (derived from moduli and density) for material
+    ($struct_name:ident, elastic_gpa:[$min_gpa:expr, $max_gpa:expr], poisson:[$min_poisson:expr, $max_poisson:expr]) => {
+        impl Elastic for $struct_name {
+            fn get_young_modulus_avg_gpa(&self) -> GPa {
+                ($min_gpa + $max_gpa) * 0.5
+            }
+            fn get_poisson_ratio_avg(&self) -> f32 {
+                ($min_poisson + $max_poisson) * 0.5
+            }
+        }
+        impl Acoustic for $struct_name {
+            fn get_sound_longitudinal_ms(&self) -> Ms {
+                let (e, nu) = (self.get_young_modulus_avg_gpa() * 1.0e9, self.get_poisson_ratio_avg());
+                (e * (1.0 - nu) / (self.get_density_avg_kgm3() * (1.0 + nu) * (1.0 - 2.0 * nu))).sqrt()
+            }
+            fn get_sound_shear_ms(&self) -> Ms {
+                (self.get_shear_modulus_gpa() * 1.0e9 / self.get_density_avg_kgm3()).sqrt()
             }
         }
     };
-    // Add HeatValue for material
-    ($struct_name:ident, heat_value_mj:[$min_mj:expr, $max_mj:expr]) => {
-        impl HeatValue for $struct_name {
-            fn get_heat_value_avg_kkal(&self) -> kkal_kg {
-                ($min_mj + $max_mj) * 0.5
+    // Add Acoustic (measured) for material, excludes elastic_gpa which derives Acoustic
+    ($struct_name:ident, sound_ms:[longitudinal: [$min_l:expr, $max_l:expr], shear: [$min_s:expr, $max_s:expr]]) => {
+        impl Acoustic for $struct_name {
+            fn get_sound_longitudinal_ms(&self) -> Ms {
+                ($min_l + $max_l) * 0.5
             }
-            fn get_heat_value_avg_mj(&self) -> MJ_kg {
-                (($min_mj + $max_mj) * 0.5) * 238.8458966275
+            fn get_sound_shear_ms(&self) -> Ms {
+                ($min_s + $max_s) * 0.5
             }
         }
     };
+    // Add Seismic for rock, P- and S-wave velocities are the longitudinal and shear sound speeds
+    ($struct_name:ident, seismic:[vp: [$min_vp:expr, $max_vp:expr], vs: [$min_vs:expr, $max_vs:expr]]) => {
+        impl Seismic for $struct_name {
+            fn get_vp_min_ms(&self) -> Ms {$min_vp}
+            fn get_vp_max_ms(&self) -> Ms {$max_vp}
+            fn get_vs_min_ms(&self) -> Ms {$min_vs}
+            fn get_vs_max_ms(&self) -> Ms {$max_vs}
+        }
+    };
     // Add Element for material
     ($struct_name:ident, $number:expr, $symbol:literal) => {
         impl Element for $struct_name {
@@ -257,6 +1241,24 @@ macro_rules! mat_add {
     }
 }
 
+pub mod amount;
+pub mod buoyancy;
+pub mod coal;
+pub mod combustion;
+pub mod eos;
+pub mod explosion;
+pub mod fire;
+pub mod hydrostatic;
+pub mod ignition;
+pub mod precious;
+pub mod shape;
+pub mod water;
+
+use coal::{CoalRank, ProximateAnalysis};
+use combustion::FuelComposition;
+use eos::PengRobinson;
+use precious::{Fineness, Hallmark, PreciousMetal};
+
 /// Number of material structures
 pub const COUNT_MATERIALS:u32 = ALL_MATERIALS.len() as u32;
 /// SLang size
@@ -266,19 +1268,71 @@ pub const ALL_MATERIALS: &[&dyn Material] = &[
     &Granite,
     &Obsidian,
     &BrownCoal,
+    &SubBituminousCoal,
+    &BituminousCoal,
+    &Anthracite,
     &Eclogite,
 
     &Hydrogen,
     &Gold,
+    &Silver,
+    &Platinum,
     &Iron,
     &Aluminium,
     &Copper,
     &Zinc,
     &Magnesium,
     &Manganese,
+    &Nitrogen,
+    &Oxygen,
+    &Helium,
+    &Argon,
 
     &Brass,
     &Dural,
+    &Gold750,
+    &Gold585,
+    &SterlingSilver,
+    &Platinum950,
+
+    &Oak,
+    &Birch,
+    &Pine,
+    &Spruce,
+    &Peat,
+    &Charcoal,
+    &Gasoline,
+    &Diesel,
+    &Kerosene,
+    &Ethanol,
+    &Methane,
+    &Propane,
+    &Butane,
+    &Ethane,
+    &CarbonMonoxide,
+
+    &Water,
+    &SeaWater,
+    &EngineOil,
+    &OliveOil,
+    &Mercury,
+    &MoltenIron,
+    &MoltenAluminium,
+
+    &Ice,
+    &Steam,
+    &CarbonDioxide,
+    &Quartz,
+    &Calcite,
+    &CementStone,
+
+    &Air,
+    &NaturalGas,
+    &Syngas,
+    &FlueGas,
+
+    &Concrete,
+    &Sandstone,
 ];
 
 /// SLang = Supported Language. ISO 639-1
@@ -287,7 +1341,7 @@ pub enum SLang {
     EN = 45,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Rock groups by formation
 pub enum GroupRock {
     /// Result of compaction of sediment on the bottom of rivers, seas and oceans, and the destruction/weathering of various rocks on land.<br>
@@ -322,6 +1376,29 @@ pub enum SubgroupSedimentary {
     Clastic,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Fuel groups
+pub enum GroupFuel {
+    /// Coal of any rank, from lignite to anthracite.<br>
+    /// Ископаемый уголь любой стадии, от бурого до антрацита.
+    Coal,
+    /// Partially decomposed plant matter of bogs.<br>
+    /// Торф - частично разложившиеся растительные остатки болот.
+    Peat,
+    /// Firewood.<br>
+    /// Древесина, дрова.
+    Wood,
+    /// Product of wood pyrolysis.<br>
+    /// Древесный уголь - продукт пиролиза древесины.
+    Charcoal,
+    /// Liquid fuels.<br>
+    /// Жидкое топливо.
+    Liquid,
+    /// Gaseous fuels.<br>
+    /// Газообразное топливо.
+    Gas,
+}
+
 pub enum GroupMetal {
     // Щелочные
     Alkali,
@@ -333,6 +1410,117 @@ pub enum GroupMetal {
     PostTransition,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// State of matter
+pub enum Phase {
+    /// Solid.<br>
+    /// Твёрдое тело.
+    Solid,
+    /// Liquid.<br>
+    /// Жидкость.
+    Liquid,
+    /// Gas or vapour.<br>
+    /// Газ или пар.
+    Gas,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Response of a material to an external magnetic field at room temperature
+pub enum MagneticClass {
+    /// Weakly repelled by a magnetic field, χ < 0. Copper, gold, water.<br>
+    /// Диамагнетик - слабо выталкивается из магнитного поля, χ < 0. Медь, золото, вода.
+    Diamagnetic,
+    /// Weakly attracted by a magnetic field, χ > 0. Aluminium, magnesium.<br>
+    /// Парамагнетик - слабо втягивается в магнитное поле, χ > 0. Алюминий, магний.
+    Paramagnetic,
+    /// Strongly attracted, keeps magnetization below the Curie temperature. Iron.<br>
+    /// Ферромагнетик - сильно втягивается, сохраняет намагниченность ниже точки Кюри. Железо.
+    Ferromagnetic,
+    /// Magnetization of unequal opposing sublattices, magnetite-bearing rocks.<br>
+    /// Ферримагнетик - намагниченность неравных противоположных подрешёток, породы с магнетитом.
+    Ferrimagnetic,
+    /// Opposing sublattices cancel out, magnetization is near zero.<br>
+    /// Антиферромагнетик - противоположные подрешётки компенсируют друг друга.
+    Antiferromagnetic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Renderer-agnostic parameters for physically based rendering (metallic-roughness workflow)
+pub struct Pbr {
+    /// Base color (albedo for dielectrics, specular color for metals), linear sRGB, 0..1
+    pub base_color: [f32; 3],
+    /// 1.0 for metals, 0.0 for dielectrics
+    pub metallic: f32,
+    /// Typical surface roughness, 0 - mirror, 1 - fully diffuse
+    pub roughness: f32,
+    /// Index of refraction (real part at ~550 nm for metals)
+    pub ior: f32,
+    /// Fraction of light transmitted through the material, 0..1
+    pub transmission: f32,
+}
+
+impl Pbr {
+    /// Opacity (alpha), 1.0 - fully opaque
+    pub fn get_opacity(&self) -> f32 {
+        1.0 - self.transmission
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Basis of a heating value of a solid fuel
+pub enum HeatBasis {
+    /// Fuel as received, with its moisture and ash.<br>
+    /// На рабочую массу, с влагой и золой.
+    AsReceived,
+    /// Moisture removed.<br>
+    /// На сухую массу.
+    Dry,
+    /// Moisture and ash removed.<br>
+    /// На сухую беззольную (горючую) массу.
+    DryAshFree,
+}
+
+impl HeatBasis {
+    /// Share of the as-received mass left on this basis, `moisture` and `ash` are as-received mass %
+    fn get_mass_share(self, moisture: Percent, ash: Percent) -> f32 {
+        let (moisture, ash) = combustion::limit_moisture_ash(moisture, ash);
+        match self {
+            HeatBasis::AsReceived => 1.0,
+            HeatBasis::Dry => 1.0 - moisture * 0.01,
+            HeatBasis::DryAshFree => 1.0 - (moisture + ash) * 0.01,
+        }
+    }
+    /// Converts a higher heating value from this basis to `to`. <br>
+    /// `moisture` and `ash` are as-received mass %.
+    pub fn convert_hhv(self, hhv: MJ_kg, to: HeatBasis, moisture: Percent, ash: Percent) -> MJ_kg {
+        hhv * self.get_mass_share(moisture, ash) / to.get_mass_share(moisture, ash)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Temperature and pressure a tabulated property is given at
+pub struct ReferenceConditions {
+    pub temperature: Celsius,
+    pub pressure: KPa,
+}
+
+impl ReferenceConditions {
+    /// Standard temperature and pressure, 0 °C and 101.325 kPa. Gases
+    pub const STP: ReferenceConditions = ReferenceConditions {temperature: 0.0, pressure: 101.325};
+    /// Room conditions, 20 °C and 101.325 kPa. Solids and liquids
+    pub const ROOM: ReferenceConditions = ReferenceConditions {temperature: 20.0, pressure: 101.325};
+    pub fn get_temperature_k(&self) -> Kelvin {
+        self.temperature + 273.15
+    }
+}
+
+/// Lower heating value from the higher one, both on the same basis. <br>
+/// `hydrogen` and `moisture` are mass % on that basis, 2.442 MJ/kg is the latent heat of water at 25 °C.
+pub fn get_lhv_from_hhv(hhv: MJ_kg, hydrogen: Percent, moisture: Percent) -> MJ_kg {
+    // 1 kg of hydrogen burns to 8.937 kg of water
+    hhv - 2.442 * (8.937 * hydrogen + moisture) * 0.01
+}
+
 pub trait Material {
     /// Возвращает прогресс локализации. <br>
     /// Увеличивается на 1 за каждое переведенное SLang. <br>
@@ -340,24 +1528,403 @@ pub trait Material {
     fn get_progress_locale_name(&self) -> u16 {0}
     /// Возвращает локализованное название материала
     fn get_name(&self, _lang:SLang) -> &'static str {""}
+    /// Density, every material has it
+    fn as_density(&self) -> Option<&dyn Density> {None}
+    /// Chemical element, elements only
+    fn as_element(&self) -> Option<&dyn Element> {None}
+    /// Ignition temperature, if the material burns
+    fn as_ignition(&self) -> Option<&dyn Ignition> {None}
+    /// Burning temperature, if the material burns
+    fn as_burning(&self) -> Option<&dyn Burning> {None}
+    /// Heating value, if the material burns
+    fn as_heat_value(&self) -> Option<&dyn HeatValue> {None}
+    /// Electrical resistivity, conductors only
+    fn as_electrical(&self) -> Option<&dyn Electrical> {None}
+    /// Magnetic properties, if known
+    fn as_magnetic(&self) -> Option<&dyn Magnetic> {None}
+    /// Appearance for rendering, if known
+    fn as_appearance(&self) -> Option<&dyn Appearance> {None}
+    /// Thermal emissivity, if known
+    fn as_emissivity(&self) -> Option<&dyn Emissivity> {None}
+    /// Heat conduction and storage, if known
+    fn as_thermal(&self) -> Option<&dyn Thermal> {None}
+    /// Elastic moduli, if known
+    fn as_elastic(&self) -> Option<&dyn Elastic> {None}
+    /// Speed of sound, if known
+    fn as_acoustic(&self) -> Option<&dyn Acoustic> {None}
+    /// Ideal gas properties, gases only
+    fn as_gas(&self) -> Option<&dyn Gas> {None}
+    /// Heating value per volume, gas fuels only
+    fn as_gas_heat_value(&self) -> Option<&dyn GasHeatValue> {None}
+    /// Composition, gas mixtures only
+    fn as_gas_mixture(&self) -> Option<&dyn GasMixture> {None}
+    /// Normal boiling point, if known
+    fn as_boiling(&self) -> Option<&dyn Boiling> {None}
+    /// Viscosity and surface tension, liquids only
+    fn as_liquid(&self) -> Option<&dyn Liquid> {None}
+    /// Vapour pressure, liquids only
+    fn as_vapour_pressure(&self) -> Option<&dyn VapourPressure> {None}
+    /// Heats of melting and boiling, if known
+    fn as_latent_heat(&self) -> Option<&dyn LatentHeat> {None}
+    /// Constituents and pores of a composite material
+    fn as_composite(&self) -> Option<&dyn Composite> {None}
+    /// Precious metal and its fineness, pure metals and jewellery alloys
+    fn as_precious(&self) -> Option<&dyn Precious> {None}
+    /// Seismic velocities, rocks only
+    fn as_seismic(&self) -> Option<&dyn Seismic> {None}
+    /// Flammable range, gases and vapours only
+    fn as_flammability(&self) -> Option<&dyn Flammability> {None}
+    /// Fuel properties, if the material is a fuel
+    fn as_fuel(&self) -> Option<&dyn Fuel> {None}
+}
+
+/// Fuels from `ALL_MATERIALS` of the given group
+pub fn get_fuels_by_group(group: GroupFuel) -> Vec<&'static dyn Material> {
+    ALL_MATERIALS
+        .iter()
+        .copied()
+        .filter(|material| material.as_fuel().is_some_and(|fuel| fuel.get_fuel_group() == group))
+        .collect()
+}
+
+/// Rock from `ALL_MATERIALS` matched against measured seismic data
+pub struct RockCandidate {
+    pub rock: &'static dyn Material,
+    /// exp(-χ²/2) of the measurement against the rock ranges, 1.0 - inside the typical values
+    pub likelihood: f32,
+    /// Likelihood normalized over all candidates (equal priors)
+    pub probability: f32,
+}
+
+/// Ranks rocks from `ALL_MATERIALS` by how well they explain the measured P-wave velocity `vp`,
+/// S-wave velocity `vs` and bulk `density`. <br>
+/// Each tabulated range is treated as mean ± 2σ of a normal distribution.
+/// `group` limits candidates to one `GroupRock`. Sorted from the most likely.
+pub fn rank_rocks_by_seismic(vp: Ms, vs: Ms, density: Gsm3, group: Option<GroupRock>) -> Vec<RockCandidate> {
+    fn chi2(value: f32, min: f32, max: f32) -> f32 {
+        let mean = (min + max) * 0.5;
+        // narrow ranges still have a measurement spread of a few percent
+        let sigma = ((max - min) * 0.25).max(mean.abs() * 0.02);
+        ((value - mean) / sigma).powi(2)
+    }
+    let mut candidates: Vec<RockCandidate> = ALL_MATERIALS
+        .iter()
+        .filter_map(|material| {
+            let seismic = material.as_seismic()?;
+            if group.is_some_and(|group| group != seismic.get_group()) {
+                return None;
+            }
+            let chi2 = chi2(vp, seismic.get_vp_min_ms(), seismic.get_vp_max_ms())
+                + chi2(vs, seismic.get_vs_min_ms(), seismic.get_vs_max_ms())
+                + chi2(density, seismic.get_density_min_gcm3(), seismic.get_density_max_gcm3());
+            Some(RockCandidate {rock: *material, likelihood: (-0.5 * chi2).exp(), probability: 0.0})
+        })
+        .collect();
+    let total: f32 = candidates.iter().map(|candidate| candidate.likelihood).sum();
+    if total > 0.0 {
+        for candidate in candidates.iter_mut() {
+            candidate.probability = candidate.likelihood / total;
+        }
+    }
+    candidates.sort_by(|a, b| b.likelihood.total_cmp(&a.likelihood));
+    candidates
+}
+
+/// kPa in 1 mmHg
+pub const MMHG_IN_KPA: f32 = 0.133_322_4;
+/// Thermal conductivity of still air in dry pores, W/(m·K)
+pub const AIR_CONDUCTIVITY_WMK: WmK = 0.026;
+/// Molar gas constant, J/(mol·K)
+pub const GAS_CONSTANT: f32 = 8.314_462;
+
+/// Stefan–Boltzmann constant, W/(m²·K⁴)
+pub const STEFAN_BOLTZMANN: f32 = 5.670_374e-8;
+
+/// Color of a black body at temperature `t`, linear sRGB normalized to the brightest channel. <br>
+/// The color is chromaticity only, use `Emissivity::get_radiated_power_wm2` for the brightness.
+/// Below ~800 K (Draper point) the glow is invisible to the eye, but the color is still returned.
+pub fn get_incandescent_color(t: Kelvin) -> [f32; 3] {
+    // Multi-lobe gaussian fit of the CIE 1931 color matching functions (Wyman, Sloan, Shirley 2013)
+    fn lobe(lambda: f64, mu: f64, sigma_lo: f64, sigma_hi: f64) -> f64 {
+        let sigma = if lambda < mu {sigma_lo} else {sigma_hi};
+        (-0.5 * ((lambda - mu) / sigma).powi(2)).exp()
+    }
+    let t = t as f64;
+    if t <= 0.0 {
+        return [0.0, 0.0, 0.0];
+    }
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    let mut lambda = 380.0_f64;
+    while lambda <= 780.0 {
+        // Planck's law up to a constant factor, λ in nm, c2 = 1.4388e7 nm·K
+        let planck = 1.0 / (lambda.powi(5) * ((1.4388e7 / (lambda * t)).exp() - 1.0));
+        x += planck * (1.056 * lobe(lambda, 599.8, 37.9, 31.0) + 0.362 * lobe(lambda, 442.0, 16.0, 26.7)
+            - 0.065 * lobe(lambda, 501.1, 20.4, 26.2));
+        y += planck * (0.821 * lobe(lambda, 568.8, 46.9, 40.5) + 0.286 * lobe(lambda, 530.9, 16.3, 31.1));
+        z += planck * (1.217 * lobe(lambda, 437.0, 11.8, 36.0) + 0.681 * lobe(lambda, 459.0, 26.0, 13.8));
+        lambda += 5.0;
+    }
+    let rgb = [
+        (3.2406 * x - 1.5372 * y - 0.4986 * z).max(0.0),
+        (-0.9689 * x + 1.8758 * y + 0.0415 * z).max(0.0),
+        (0.0557 * x - 0.2040 * y + 1.0570 * z).max(0.0),
+    ];
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    if !max.is_normal() {
+        return [0.0, 0.0, 0.0];
+    }
+    [(rgb[0] / max) as f32, (rgb[1] / max) as f32, (rgb[2] / max) as f32]
+}
+
+/// Pressure amplitude reflection coefficient for a wave going from `from` into `to` at normal incidence. <br>
+/// R = (Z2 - Z1) / (Z2 + Z1), the reflected energy fraction is R².
+pub fn get_reflection_coefficient(from: &dyn Acoustic, to: &dyn Acoustic) -> f32 {
+    let (z1, z2) = (from.get_acoustic_impedance_rayl(), to.get_acoustic_impedance_rayl());
+    (z2 - z1) / (z2 + z1)
+}
+
+/// Pressure amplitude transmission coefficient for a wave going from `from` into `to` at normal incidence. <br>
+/// T = 2·Z2 / (Z2 + Z1), the transmitted energy fraction is 1 - R².
+pub fn get_transmission_coefficient(from: &dyn Acoustic, to: &dyn Acoustic) -> f32 {
+    let (z1, z2) = (from.get_acoustic_impedance_rayl(), to.get_acoustic_impedance_rayl());
+    2.0 * z2 / (z2 + z1)
+}
+
+/// PBR parameters of any material, `None` if the material has no `Appearance`
+pub fn get_pbr(material: &dyn Material) -> Option<Pbr> {
+    material.as_appearance().map(|appearance| appearance.get_pbr())
+}
+
+/// Materials from `ALL_MATERIALS` with the given magnetic class
+pub fn get_materials_by_magnetic_class(class: MagneticClass) -> Vec<&'static dyn Material> {
+    ALL_MATERIALS
+        .iter()
+        .copied()
+        .filter(|material| {
+            material
+                .as_magnetic()
+                .is_some_and(|magnetic| magnetic.get_magnetic_class() == class)
+        })
+        .collect()
 }
 
 /// Density, g/cm3 and kg/m3
 pub trait Density {
+    /// Conditions the density is tabulated at
+    fn get_density_conditions(&self) -> ReferenceConditions {ReferenceConditions::ROOM}
+    fn get_density_min_gcm3(&self) -> Gsm3 {0.0}
+    fn get_density_max_gcm3(&self) -> Gsm3 {0.0}
     fn get_density_avg_gcm3(&self) -> Gsm3 {0.0}
     fn get_density_avg_kgm3(&self) -> KGm3 {0.0}
 }
 
+/// Gas, ideal and real (Peng–Robinson) equation of state
+pub trait Gas: Density {
+    /// Molar mass, g/mol
+    fn get_molar_mass_g_mol(&self) -> GMol;
+    /// Critical temperature, K
+    fn get_critical_temperature_k(&self) -> Kelvin;
+    /// Critical pressure, kPa
+    fn get_critical_pressure_kpa(&self) -> KPa;
+    /// Pitzer acentric factor ω
+    fn get_acentric_factor(&self) -> f32;
+    /// Specific gas constant R/M, J/(kg·K)
+    fn get_gas_constant_jkgk(&self) -> JkgK {
+        GAS_CONSTANT * 1000.0 / self.get_molar_mass_g_mol()
+    }
+    /// Density at temperature `t` and pressure `p`, kg/m³. <br>
+    /// Ideal gas, see `get_density_conditions` for the conditions of the tabulated density
+    fn get_density_kgm3_at_tp(&self, t: Kelvin, p: KPa) -> KGm3 {
+        p * 1000.0 / (self.get_gas_constant_jkgk() * t)
+    }
+    fn get_equation_of_state(&self) -> PengRobinson {
+        PengRobinson {
+            critical_temperature: self.get_critical_temperature_k(),
+            critical_pressure: self.get_critical_pressure_kpa(),
+            acentric_factor: self.get_acentric_factor(),
+        }
+    }
+    /// Compressibility factor Z at temperature `t` and pressure `p` (Peng–Robinson), 1.0 - ideal gas
+    fn get_compressibility_at_tp(&self, t: Kelvin, p: KPa) -> f32 {
+        self.get_equation_of_state().get_compressibility(t, p)
+    }
+    /// Density at temperature `t` and pressure `p` (Peng–Robinson), kg/m³. <br>
+    /// Liquid density below the boiling point
+    fn get_real_density_kgm3_at_tp(&self, t: Kelvin, p: KPa) -> KGm3 {
+        self.get_density_kgm3_at_tp(t, p) / self.get_compressibility_at_tp(t, p)
+    }
+    /// Mass held by a tank of `volume` at temperature `t` and pressure `p` (Peng–Robinson), kg
+    fn get_stored_mass_kg(&self, volume: CubicMeter, t: Kelvin, p: KPa) -> Kilogram {
+        self.get_real_density_kgm3_at_tp(t, p) * volume
+    }
+}
+/// Mixture of gases with a fixed molar (for ideal gases - volume) composition
+pub trait GasMixture: Gas {
+    /// Components with their mole %, adding up to 100
+    fn get_mole_composition(&self) -> &'static [(&'static dyn Material, Percent)];
+    /// Components with their mass %
+    fn get_mass_composition(&self) -> Vec<(&'static dyn Material, Percent)> {
+        let molar_mass = self.get_molar_mass_g_mol();
+        self.get_mole_composition()
+            .iter()
+            .map(|&(component, pct)| {
+                (component, pct * component.as_gas().map_or(0.0, |gas| gas.get_molar_mass_g_mol()) / molar_mass)
+            })
+            .collect()
+    }
+    /// Mole-weighted average of a property of the gas components
+    fn get_mole_average(&self, property: &dyn Fn(&dyn Gas) -> f32) -> f32 {
+        self.get_mole_composition()
+            .iter()
+            .map(|(component, pct)| component.as_gas().map_or(0.0, property) * pct * 0.01)
+            .sum()
+    }
+    /// Mass-weighted average of a property of the components
+    fn get_mass_average(&self, property: &dyn Fn(&dyn Material) -> f32) -> f32 {
+        self.get_mass_composition().iter().map(|(component, pct)| property(*component) * pct * 0.01).sum()
+    }
+    /// Flammability limit of the mixture in air by Le Chatelier's rule, % by volume:
+    /// L = 100 / Σ(yᵢ/Lᵢ) over the combustible components. `None` - nothing burns
+    fn get_le_chatelier_pct(&self, limit: &dyn Fn(&dyn Flammability) -> Percent) -> Option<Percent> {
+        let sum: f32 = self
+            .get_mole_composition()
+            .iter()
+            .filter_map(|(component, pct)| component.as_flammability().map(|flammability| pct / limit(flammability)))
+            .sum();
+        (sum > 0.0).then(|| (100.0 / sum).min(100.0))
+    }
+}
+/// Composite or porous material: solid constituents, pores and the fluid that fills them
+pub trait Composite {
+    /// Solid constituents with their % of the solid volume, adding up to 100
+    fn get_constituents(&self) -> &'static [(&'static dyn Thermal, Percent)];
+    /// Pore volume, % of the bulk volume
+    fn get_porosity_pct(&self) -> Percent;
+    /// Fluid in the pores of the saturated material
+    fn get_pore_fluid(&self) -> &'static dyn Thermal;
+    /// Share of the pores filled with the pore fluid in the natural state, %
+    fn get_saturation_pct(&self) -> Percent;
+    /// Density of the solid skeleton without pores, kg/m³
+    fn get_solid_density_kgm3(&self) -> KGm3 {
+        self.get_constituents()
+            .iter()
+            .map(|(constituent, pct)| constituent.get_density_avg_kgm3() * pct * 0.01)
+            .sum()
+    }
+    /// Density with empty pores, kg/m³
+    fn get_dry_density_kgm3(&self) -> KGm3 {
+        self.get_solid_density_kgm3() * (1.0 - self.get_porosity_pct() * 0.01)
+    }
+    /// Density with `saturation` % of the pores filled with the pore fluid, kg/m³
+    fn get_bulk_density_kgm3(&self, saturation: Percent) -> KGm3 {
+        let fluid = self.get_pore_fluid().get_density_avg_kgm3();
+        self.get_dry_density_kgm3() + fluid * self.get_porosity_pct() * 0.01 * saturation * 0.01
+    }
+    /// Density with all pores filled with the pore fluid, kg/m³
+    fn get_saturated_density_kgm3(&self) -> KGm3 {
+        self.get_bulk_density_kgm3(100.0)
+    }
+    /// Thermal conductivity with `saturation` % of the pores filled with the pore fluid
+    /// and the rest with air, W/(m·K). <br>
+    /// Geometric mean of the constituents weighted by their volume (Lichtenecker)
+    fn get_effective_conductivity_wmk(&self, saturation: Percent) -> WmK {
+        let conductivity = |material: &dyn Thermal| material.get_thermal_conductivity_avg_wmk();
+        let porosity = self.get_porosity_pct() * 0.01;
+        let filled = porosity * saturation * 0.01;
+        let ln_solid: f32 = self.get_constituents().iter().map(|(constituent, pct)| conductivity(*constituent).ln() * pct * 0.01).sum();
+        (ln_solid * (1.0 - porosity) + conductivity(self.get_pore_fluid()).ln() * filled + AIR_CONDUCTIVITY_WMK.ln() * (porosity - filled)).exp()
+    }
+    /// Specific heat with `saturation` % of the pores filled with the pore fluid, J/(kg·K)
+    fn get_specific_heat_jkgk(&self, saturation: Percent) -> JkgK {
+        let heat = |material: &dyn Thermal| material.get_density_avg_kgm3() * material.get_specific_heat_avg_jkgk();
+        let solid: f32 = self.get_constituents().iter().map(|(constituent, pct)| heat(*constituent) * pct * 0.01).sum();
+        let porosity = self.get_porosity_pct() * 0.01;
+        // heat capacity per bulk volume over the bulk density
+        (solid * (1.0 - porosity) + heat(self.get_pore_fluid()) * porosity * saturation * 0.01) / self.get_bulk_density_kgm3(saturation)
+    }
+}
+/// Chemical compound
+pub trait Compound {
+    /// Chemical formula, e.g. "CO2"
+    fn get_formula(&self) -> &'static str;
+}
 /// Melting point, Kelvin and Celsius °C
 pub trait Melting {
     fn get_melting_avg_c(&self) -> Celsius;
     fn get_melting_avg_k(&self) -> Kelvin;
 }
 
+/// Boiling point at 101.325 kPa, Kelvin and Celsius °C
+pub trait Boiling {
+    fn get_boiling_avg_c(&self) -> Celsius;
+    fn get_boiling_avg_k(&self) -> Kelvin;
+}
+/// Latent heats of phase changes at 101.325 kPa
+pub trait LatentHeat {
+    /// Heat of fusion at the melting point, kJ/kg
+    fn get_fusion_kj_kg(&self) -> KJ_kg;
+    /// Heat of vaporization at the boiling point, kJ/kg. <br>
+    /// `None` for a solid phase, see `get_sublimation_kj_kg`.
+    fn get_vaporization_kj_kg(&self) -> Option<KJ_kg>;
+    /// Heat of sublimation at the melting point, kJ/kg
+    fn get_sublimation_kj_kg(&self) -> Option<KJ_kg> {None}
+}
+/// Liquid, viscosity and surface tension depend on temperature
+pub trait Liquid: Density {
+    /// Vogel equation constants [A, B, C] of μ = A·10^(B/(T - C)): Pa·s, K, K
+    fn get_viscosity_vogel(&self) -> [f32; 3];
+    /// Dynamic viscosity at temperature `t`, Pa·s. <br>
+    /// `None` at or below the Vogel temperature C, where the equation has no meaning.
+    fn get_viscosity_pa_s(&self, t: Kelvin) -> Option<Pa_s> {
+        let [a, b, c] = self.get_viscosity_vogel();
+        let viscosity = a * 10.0_f32.powf(b / (t - c));
+        (t > c && viscosity.is_finite()).then_some(viscosity)
+    }
+    /// Kinematic viscosity ν = μ/ρ at temperature `t` (tabulated density), m²/s. <br>
+    /// `None` as for `get_viscosity_pa_s`.
+    fn get_kinematic_viscosity_m2s(&self, t: Kelvin) -> Option<f32> {
+        Some(self.get_viscosity_pa_s(t)? / self.get_density_avg_kgm3())
+    }
+    /// Surface tension against air at temperature `t`, N/m
+    fn get_surface_tension_n_m(&self, t: Kelvin) -> N_m;
+}
+/// Saturated vapour pressure by the Antoine equation
+pub trait VapourPressure {
+    /// Antoine constants [A, B, C] of log10(p) = A - B/(C + t), p in mmHg, t in °C
+    fn get_antoine_mmhg_c(&self) -> [f32; 3];
+    /// Saturated vapour pressure at temperature `t`, kPa
+    fn get_vapour_pressure_kpa(&self, t: Kelvin) -> KPa {
+        let [a, b, c] = self.get_antoine_mmhg_c();
+        10.0_f32.powf(a - b / (c + t - 273.15)) * MMHG_IN_KPA
+    }
+    /// Boiling temperature at pressure `p`, °C
+    fn get_boiling_c_at(&self, p: KPa) -> Celsius {
+        let [a, b, c] = self.get_antoine_mmhg_c();
+        b / (a - (p / MMHG_IN_KPA).log10()) - c
+    }
+}
 pub trait Rock {
     fn get_group(&self) -> GroupRock;
 }
 
+/// Seismic P- and S-wave velocities of a rock, m/s
+pub trait Seismic: Rock + Density {
+    fn get_vp_min_ms(&self) -> Ms;
+    fn get_vp_max_ms(&self) -> Ms;
+    fn get_vs_min_ms(&self) -> Ms;
+    fn get_vs_max_ms(&self) -> Ms;
+    fn get_vp_avg_ms(&self) -> Ms {
+        (self.get_vp_min_ms() + self.get_vp_max_ms()) * 0.5
+    }
+    fn get_vs_avg_ms(&self) -> Ms {
+        (self.get_vs_min_ms() + self.get_vs_max_ms()) * 0.5
+    }
+    /// Vp/Vs ratio, ~1.73 for a Poisson solid
+    fn get_vp_vs_ratio(&self) -> f32 {
+        self.get_vp_avg_ms() / self.get_vs_avg_ms()
+    }
+}
+
 /// Sedimentary rock
 pub trait Sedimentary {
     fn get_subgroup(&self) -> SubgroupSedimentary;
@@ -375,14 +1942,248 @@ pub trait Ignition {
     fn get_ignition_avg_c(&self) -> Celsius {0.0}
     fn get_ignition_avg_k(&self) -> Kelvin {0.0}
 }
+/// Fuel whose heating value, density and flame temperature depend on moisture and ash. <br>
+/// Moisture and ash are as-received mass %.
+pub trait Fuel: HeatValue + UltimateAnalysis {
+    fn get_fuel_group(&self) -> GroupFuel;
+    /// Typical as-received moisture, %. The tabulated density is given at this moisture
+    fn get_moisture_typical_pct(&self) -> Percent;
+    /// Typical as-received ash, %
+    fn get_ash_typical_pct(&self) -> Percent;
+    /// Elemental composition as received at `moisture` and `ash`
+    fn get_composition_at(&self, moisture: Percent, ash: Percent) -> FuelComposition {
+        self.get_ultimate_analysis().with_moisture_ash(moisture, ash)
+    }
+    /// Higher heating value as received at `moisture` and `ash`, MJ/kg
+    fn get_hhv_mj_at(&self, moisture: Percent, ash: Percent) -> MJ_kg {
+        let tabulated = self.get_ultimate_analysis();
+        let dry_ash_free = self.get_heat_value_basis().convert_hhv(
+            self.get_hhv_avg_mj(),
+            HeatBasis::DryAshFree,
+            tabulated.moisture,
+            tabulated.ash,
+        );
+        HeatBasis::DryAshFree.convert_hhv(dry_ash_free, HeatBasis::AsReceived, moisture, ash)
+    }
+    /// Lower heating value as received at `moisture` and `ash`, MJ/kg
+    fn get_lhv_mj_at(&self, moisture: Percent, ash: Percent) -> MJ_kg {
+        let composition = self.get_composition_at(moisture, ash);
+        get_lhv_from_hhv(self.get_hhv_mj_at(moisture, ash), composition.hydrogen, moisture)
+    }
+    /// Density at `moisture`, water fills the pores without swelling, kg/m³. <br>
+    /// Moisture is limited, see `combustion::limit_moisture_ash`.
+    fn get_density_kgm3_at(&self, moisture: Percent) -> KGm3 {
+        let (moisture, _) = combustion::limit_moisture_ash(moisture, 0.0);
+        self.get_density_avg_kgm3() * (1.0 - self.get_moisture_typical_pct() * 0.01) / (1.0 - moisture * 0.01)
+    }
+    /// Volumetric energy density (lower heating value) at `moisture` and `ash`, MJ/m³
+    fn get_energy_density_mj_m3_at(&self, moisture: Percent, ash: Percent) -> MJ_m3 {
+        self.get_lhv_mj_at(moisture, ash) * self.get_density_kgm3_at(moisture)
+    }
+    /// Adiabatic flame temperature in air at 25 °C, see `FuelComposition::get_adiabatic_flame_temperature_c`.
+    /// `None` for `excess_air` below 1.0
+    fn get_burning_c_at(&self, moisture: Percent, ash: Percent, excess_air: f32) -> Option<Celsius> {
+        self.get_composition_at(moisture, ash)
+            .get_adiabatic_flame_temperature_c(self.get_lhv_mj_at(moisture, ash), excess_air, 25.0)
+    }
+}
+/// Typical proximate analysis of a coal
+pub trait CoalAnalysis: Fuel {
+    fn get_proximate_analysis(&self) -> ProximateAnalysis;
+    /// Forms a coherent coke button when heated (free swelling index > 0)
+    fn is_agglomerating(&self) -> bool;
+    /// Rank by ASTM D388, see `ProximateAnalysis::get_coal_rank`
+    fn get_coal_rank(&self) -> CoalRank {
+        self.get_proximate_analysis().get_coal_rank(self.is_agglomerating())
+    }
+}
+/// Flammable range in air and ignition sensitivity of a gas or vapour
+pub trait Flammability {
+    /// Lower flammability limit in air, % by volume
+    fn get_lfl_pct(&self) -> Percent;
+    /// Upper flammability limit in air, % by volume
+    fn get_ufl_pct(&self) -> Percent;
+    /// Minimum ignition energy of the most easily ignited mixture, mJ. `None` if unknown
+    fn get_min_ignition_energy_millijoule(&self) -> Option<MilliJoule> {None}
+    /// Autoignition temperature, ignites in air without a spark or flame
+    fn get_autoignition_avg_c(&self) -> Celsius;
+    fn get_autoignition_avg_k(&self) -> Kelvin {
+        self.get_autoignition_avg_c() + 273.15
+    }
+    /// Flash point of a liquid, `None` for gases
+    fn get_flash_point_c(&self) -> Option<Celsius> {None}
+    /// Is a mixture with `concentration` % by volume of this material in air flammable
+    fn is_flammable(&self, concentration: Percent) -> bool {
+        (self.get_lfl_pct()..=self.get_ufl_pct()).contains(&concentration)
+    }
+}
 /// Burning, max temperature
 pub trait Burning {
     fn get_burning_avg_c(&self) -> Celsius {0.0}
     fn get_burning_avg_k(&self) -> Kelvin {0.0}
 }
-pub trait HeatValue {
-    fn get_heat_value_avg_kkal(&self) -> kkal_kg {0.0}
-    fn get_heat_value_avg_mj(&self) -> MJ_kg {0.0}
+/// Heating value of a gas fuel per volume, implemented for every `Gas` with `HeatValue`
+pub trait GasHeatValue: Gas + HeatValue {
+    /// Higher heating value per m³ at `get_density_conditions`, MJ/m³
+    fn get_hhv_avg_mj_m3(&self) -> MJ_m3 {
+        self.get_hhv_avg_mj() * self.get_density_avg_kgm3()
+    }
+    /// Lower heating value per m³ at `get_density_conditions`, MJ/m³
+    fn get_lhv_avg_mj_m3(&self) -> MJ_m3 {
+        self.get_lhv_avg_mj() * self.get_density_avg_kgm3()
+    }
+}
+impl<T: Gas + HeatValue> GasHeatValue for T {}
+/// Heating value (specific energy of combustion). <br>
+/// HHV - higher/gross value, water in the flue gas is condensed. <br>
+/// LHV - lower/net value, water leaves as vapour.
+pub trait HeatValue: Density {
+    /// Higher (gross) heating value, MJ/kg
+    fn get_hhv_avg_mj(&self) -> MJ_kg;
+    /// Lower (net) heating value, MJ/kg
+    fn get_lhv_avg_mj(&self) -> MJ_kg;
+    /// Basis of the tabulated values
+    fn get_heat_value_basis(&self) -> HeatBasis;
+    fn get_hhv_avg_kkal(&self) -> kkal_kg {
+        self.get_hhv_avg_mj() * KKAL_KG_IN_MJ_KG
+    }
+    fn get_lhv_avg_kkal(&self) -> kkal_kg {
+        self.get_lhv_avg_mj() * KKAL_KG_IN_MJ_KG
+    }
+    fn get_hhv_avg_btu_lb(&self) -> BTU_lb {
+        self.get_hhv_avg_mj() * BTU_LB_IN_MJ_KG
+    }
+    fn get_lhv_avg_btu_lb(&self) -> BTU_lb {
+        self.get_lhv_avg_mj() * BTU_LB_IN_MJ_KG
+    }
+    /// Higher heating value, kcal/kg
+    #[deprecated(note = "ambiguous, use `get_hhv_avg_kkal` or `get_lhv_avg_kkal`")]
+    fn get_heat_value_avg_kkal(&self) -> kkal_kg {
+        self.get_hhv_avg_kkal()
+    }
+    /// Higher heating value, MJ/kg
+    #[deprecated(note = "ambiguous, use `get_hhv_avg_mj` or `get_lhv_avg_mj`")]
+    fn get_heat_value_avg_mj(&self) -> MJ_kg {
+        self.get_hhv_avg_mj()
+    }
+}
+/// Elemental composition of a fuel, on the same basis as its `HeatValue`
+pub trait UltimateAnalysis {
+    fn get_ultimate_analysis(&self) -> FuelComposition;
+}
+/// Electrical resistivity at 20 °C and temperature coefficient of resistance (TCR)
+pub trait Electrical {
+    /// Resistivity at 20 °C, nΩ·m
+    fn get_resistivity_avg_nohm_m(&self) -> NOhmM;
+    /// Temperature coefficient of resistance near 20 °C, 1/K
+    fn get_tcr_avg_k(&self) -> PerKelvin;
+    /// Conductivity relative to the International Annealed Copper Standard (17.241 nΩ·m = 100 %IACS)
+    fn get_conductivity_iacs(&self) -> Percent {
+        17.241 / self.get_resistivity_avg_nohm_m() * 100.0
+    }
+    /// Resistivity at temperature `t`, linear in TCR: ρ = ρ20·(1 + α·(t - 20))
+    fn get_resistivity_nohm_m(&self, t: Celsius) -> NOhmM {
+        self.get_resistivity_avg_nohm_m() * (1.0 + self.get_tcr_avg_k() * (t - 20.0))
+    }
+    /// Resistance of a conductor of `length` and `section` at temperature `t`
+    fn get_resistance_ohm(&self, length: Meter, section: Mm2, t: Celsius) -> Ohm {
+        // nΩ·m → Ω·mm²/m is a factor of 1e-3
+        self.get_resistivity_nohm_m(t) * 1.0e-3 * length / section
+    }
+    /// Voltage drop along a conductor carrying `current`. <br>
+    /// For a two-wire circuit pass the total conductor length (there and back).
+    fn get_voltage_drop_v(&self, current: Ampere, length: Meter, section: Mm2, t: Celsius) -> Volt {
+        current * self.get_resistance_ohm(length, section, t)
+    }
+}
+/// Magnetic class, volume susceptibility and Curie temperature
+pub trait Magnetic {
+    fn get_magnetic_class(&self) -> MagneticClass;
+    /// Volume magnetic susceptibility χ, SI (dimensionless)
+    fn get_susceptibility_avg_si(&self) -> f32;
+    /// Curie temperature of ferro- and ferrimagnetic materials, °C
+    fn get_curie_c(&self) -> Option<Celsius> {None}
+    /// Curie temperature of ferro- and ferrimagnetic materials, K
+    fn get_curie_k(&self) -> Option<Kelvin> {
+        self.get_curie_c().map(|curie| curie + 273.15)
+    }
+}
+/// Visual appearance of a clean, typical surface
+pub trait Appearance {
+    /// Base color, linear sRGB, 0..1
+    fn get_base_color_linear(&self) -> [f32; 3];
+    fn is_metallic(&self) -> bool;
+    /// Typical surface roughness, 0..1
+    fn get_roughness(&self) -> f32;
+    /// Index of refraction (real part at ~550 nm for metals)
+    fn get_ior(&self) -> f32;
+    /// Fraction of light transmitted through the material, 0..1
+    fn get_transmission(&self) -> f32;
+    fn get_pbr(&self) -> Pbr {
+        Pbr {
+            base_color: self.get_base_color_linear(),
+            metallic: if self.is_metallic() {1.0} else {0.0},
+            roughness: self.get_roughness(),
+            ior: self.get_ior(),
+            transmission: self.get_transmission(),
+        }
+    }
+}
+/// Thermal (total hemispherical) emissivity of a typical surface
+pub trait Emissivity {
+    /// Emissivity, 0..1 (1 - black body)
+    fn get_emissivity_avg(&self) -> f32;
+    /// Power radiated per m² of surface at temperature `t` (Stefan–Boltzmann, gray body)
+    fn get_radiated_power_wm2(&self, t: Kelvin) -> Wm2 {
+        self.get_emissivity_avg() * STEFAN_BOLTZMANN * t.powi(4)
+    }
+    /// Color of the glow at temperature `t`, see `get_incandescent_color`
+    fn get_glow_color(&self, t: Kelvin) -> [f32; 3] {
+        get_incandescent_color(t)
+    }
+}
+/// Heat conduction and storage at room temperature
+pub trait Thermal: Density {
+    /// Thermal conductivity λ, W/(m·K)
+    fn get_thermal_conductivity_avg_wmk(&self) -> WmK;
+    /// Specific heat capacity c, J/(kg·K)
+    fn get_specific_heat_avg_jkgk(&self) -> JkgK;
+    /// Thermal inertia λ·ρ·c, W²·s/(m⁴·K²). <br>
+    /// The lower it is, the faster the surface heats up under a heat flux
+    fn get_thermal_inertia(&self) -> f32 {
+        self.get_thermal_conductivity_avg_wmk() * self.get_density_avg_kgm3() * self.get_specific_heat_avg_jkgk()
+    }
+    /// Thermal diffusivity a = λ/(ρ·c), m²/s
+    fn get_thermal_diffusivity_m2s(&self) -> f32 {
+        self.get_thermal_conductivity_avg_wmk() / (self.get_density_avg_kgm3() * self.get_specific_heat_avg_jkgk())
+    }
+}
+/// Elastic moduli of an isotropic solid
+pub trait Elastic {
+    /// Young's modulus, GPa
+    fn get_young_modulus_avg_gpa(&self) -> GPa;
+    fn get_poisson_ratio_avg(&self) -> f32;
+    /// Shear modulus G = E / (2·(1 + ν)), GPa
+    fn get_shear_modulus_gpa(&self) -> GPa {
+        self.get_young_modulus_avg_gpa() / (2.0 * (1.0 + self.get_poisson_ratio_avg()))
+    }
+    /// Bulk modulus K = E / (3·(1 - 2ν)), GPa
+    fn get_bulk_modulus_gpa(&self) -> GPa {
+        self.get_young_modulus_avg_gpa() / (3.0 * (1.0 - 2.0 * self.get_poisson_ratio_avg()))
+    }
+}
+/// Speed of sound in bulk material. <br>
+/// Measured values (`sound_ms`) or derived from `Elastic` and `Density` (`elastic_gpa`),
+/// a material has one or the other, never both.
+pub trait Acoustic: Density {
+    /// Longitudinal (P) wave speed, m/s
+    fn get_sound_longitudinal_ms(&self) -> Ms;
+    /// Shear (S) wave speed, m/s. 0 for fluids
+    fn get_sound_shear_ms(&self) -> Ms;
+    /// Acoustic impedance for longitudinal waves Z = ρ·v, Rayl
+    fn get_acoustic_impedance_rayl(&self) -> Rayl {
+        self.get_density_avg_kgm3() * self.get_sound_longitudinal_ms()
+    }
 }
 /// This is an element of the periodic table of chemical elements.
 pub trait Element {
@@ -394,6 +2195,16 @@ pub trait Metal {
     const GROUP_METAL: GroupMetal;
 }
 
+/// Precious metal: pure or a jewellery alloy of the given fineness
+pub trait Precious {
+    fn get_precious_metal(&self) -> PreciousMetal;
+    fn get_fineness(&self) -> Fineness;
+    /// Highest hallmark standard the fineness meets
+    fn get_hallmark(&self) -> Option<&'static Hallmark> {
+        precious::get_hallmark(self.get_precious_metal(), self.get_fineness())
+    }
+}
+
 pub trait Alloy {
     // fn get_chemical_composition() -> &'static [(&'static dyn Element, f32, f32)];
     const CHEMICAL_COMPOSITION: &'static [(&'static dyn Element, f32, f32)];
@@ -411,18 +2222,33 @@ new_rock!( Basalt,
     group: [Igneous, GroupRock::Igneous, SubgroupIgneous, SubgroupIgneous::Extrusive],
     dencity_gsm3:[2.6, 3.1],
     melting_c: [1100.0, 1250.0],
+    magnetic: [MagneticClass::Ferrimagnetic, 2.0e-4, 1.75e-1, curie_c: 580.0],
+    appearance: [color: [0.05, 0.05, 0.05], metallic: false, roughness: 0.8, ior: 1.6, transmission: 0.0],
+    emissivity: [0.72, 0.95],
+    thermal: [conductivity_wmk: [1.7, 2.5], specific_heat_jkgk: [840.0, 900.0]],
+    sound_ms: [longitudinal: [5000.0, 6400.0], shear: [2700.0, 3400.0]],
 );
 new_rock!( Granite,
     names: [SLang::RU:"Гранит", SLang::EN:"Granite"],
     group: [Igneous, GroupRock::Igneous, SubgroupIgneous, SubgroupIgneous::Intrusive],
     dencity_gsm3:[2.6, 3.0],
     melting_c: [1215.0, 1260.0],
+    magnetic: [MagneticClass::Paramagnetic, 0.0, 5.0e-2],
+    appearance: [color: [0.4, 0.35, 0.33], metallic: false, roughness: 0.6, ior: 1.55, transmission: 0.0],
+    emissivity: [0.45, 0.9],
+    thermal: [conductivity_wmk: [2.4, 3.4], specific_heat_jkgk: [790.0, 840.0]],
+    sound_ms: [longitudinal: [5500.0, 6100.0], shear: [2800.0, 3500.0]],
 );
 new_rock!( Obsidian,
     names:[SLang::RU:"Обсидиан", SLang::EN:"Obsidian"],
     group: [Igneous, GroupRock::Igneous, SubgroupIgneous, SubgroupIgneous::Extrusive],
     dencity_gsm3:[2.5, 2.6],
     melting_c: [1200.0, 1500.0],
+    magnetic: [MagneticClass::Paramagnetic, 5.0e-4, 5.0e-3],
+    appearance: [color: [0.01, 0.01, 0.012], metallic: false, roughness: 0.05, ior: 1.49, transmission: 0.05],
+    emissivity: [0.85, 0.9],
+    thermal: [conductivity_wmk: [1.2, 1.4], specific_heat_jkgk: [800.0, 900.0]],
+    sound_ms: [longitudinal: [5600.0, 6000.0], shear: [3300.0, 3600.0]],
 );
 new_rock!( BrownCoal,
     names: [SLang::RU:"Бурый уголь", SLang::EN:"Brown Coal"],
@@ -430,12 +2256,67 @@ new_rock!( BrownCoal,
     dencity_gsm3:[1.2, 1.5],
     ignition_c: [250.0, 250.0],
     burning_c: [1900.0, 1900.0],
-    heat_value_mj: [14.0, 16.0],
+    heat_value_mj: [hhv: [15.8, 18.0], lhv: [14.0, 16.0], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 40.0, h: 3.0, o: 12.0, n: 0.6, s: 0.4, moisture: 35.0, ash: 9.0],
+    fuel: [GroupFuel::Coal, moisture: [30.0, 40.0], ash: [5.0, 13.0]],
+    proximate_pct: [moisture: 35.0, volatile: 28.0, fixed_carbon: 28.0, ash: 9.0, sulfur: 0.4, agglomerating: false],
+    magnetic: [MagneticClass::Paramagnetic, 1.0e-5, 2.5e-5],
+    appearance: [color: [0.05, 0.04, 0.03], metallic: false, roughness: 0.85, ior: 1.7, transmission: 0.0],
+    emissivity: [0.8, 0.95],
+    thermal: [conductivity_wmk: [0.15, 0.25], specific_heat_jkgk: [1200.0, 1700.0]],
+    sound_ms: [longitudinal: [1500.0, 2500.0], shear: [700.0, 1200.0]],
+);
+new_rock!( SubBituminousCoal,
+    names: [SLang::RU:"Суббитуминозный уголь", SLang::EN:"Sub-bituminous Coal"],
+    group: [Sedimentary, GroupRock::Sedimentary, SubgroupSedimentary, SubgroupSedimentary::Biogenic],
+    dencity_gsm3: [1.3, 1.4],
+    ignition_c: [250.0, 300.0],
+    burning_c: [1900.0, 2000.0],
+    heat_value_mj: [hhv: [28.0, 31.0], lhv: [26.9, 29.9], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 75.0, h: 5.0, o: 18.0, n: 1.3, s: 0.7, moisture: 0.0, ash: 0.0],
+    fuel: [GroupFuel::Coal, moisture: [15.0, 30.0], ash: [5.0, 15.0]],
+    proximate_pct: [moisture: 22.5, volatile: 33.0, fixed_carbon: 34.5, ash: 10.0, sulfur: 0.5, agglomerating: false],
+    appearance: [color: [0.03, 0.028, 0.025], metallic: false, roughness: 0.7, ior: 1.75, transmission: 0.0],
+    emissivity: [0.8, 0.95],
+    thermal: [conductivity_wmk: [0.2, 0.3], specific_heat_jkgk: [1100.0, 1500.0]],
+);
+new_rock!( BituminousCoal,
+    names: [SLang::RU:"Каменный уголь", SLang::EN:"Bituminous Coal"],
+    group: [Sedimentary, GroupRock::Sedimentary, SubgroupSedimentary, SubgroupSedimentary::Biogenic],
+    dencity_gsm3: [1.2, 1.5],
+    ignition_c: [400.0, 500.0],
+    burning_c: [2000.0, 2100.0],
+    heat_value_mj: [hhv: [33.0, 36.0], lhv: [31.8, 34.8], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 85.0, h: 5.5, o: 7.0, n: 1.5, s: 1.0, moisture: 0.0, ash: 0.0],
+    fuel: [GroupFuel::Coal, moisture: [3.0, 10.0], ash: [5.0, 15.0]],
+    proximate_pct: [moisture: 6.5, volatile: 30.0, fixed_carbon: 53.5, ash: 10.0, sulfur: 0.8, agglomerating: true],
+    appearance: [color: [0.02, 0.02, 0.02], metallic: false, roughness: 0.5, ior: 1.8, transmission: 0.0],
+    emissivity: [0.8, 0.95],
+    thermal: [conductivity_wmk: [0.2, 0.35], specific_heat_jkgk: [1000.0, 1300.0]],
+);
+new_rock!( Anthracite,
+    names: [SLang::RU:"Антрацит", SLang::EN:"Anthracite"],
+    group: [Sedimentary, GroupRock::Sedimentary, SubgroupSedimentary, SubgroupSedimentary::Biogenic],
+    dencity_gsm3: [1.3, 1.8],
+    ignition_c: [450.0, 600.0],
+    burning_c: [2000.0, 2200.0],
+    heat_value_mj: [hhv: [34.0, 36.0], lhv: [33.4, 35.4], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 93.0, h: 2.8, o: 2.3, n: 1.0, s: 0.9, moisture: 0.0, ash: 0.0],
+    fuel: [GroupFuel::Coal, moisture: [2.0, 5.0], ash: [5.0, 15.0]],
+    proximate_pct: [moisture: 3.5, volatile: 5.0, fixed_carbon: 81.5, ash: 10.0, sulfur: 0.75, agglomerating: false],
+    appearance: [color: [0.015, 0.015, 0.017], metallic: false, roughness: 0.25, ior: 1.9, transmission: 0.0],
+    emissivity: [0.8, 0.95],
+    thermal: [conductivity_wmk: [0.25, 0.4], specific_heat_jkgk: [900.0, 1000.0]],
 );
 new_rock!( Eclogite,
     names: [SLang::RU:"Эклогит", SLang::EN:"Eclogite"],
     group: [Metamorphic, GroupRock::Metamorphic],
     dencity_gsm3: [3.3, 3.7],
+    magnetic: [MagneticClass::Paramagnetic, 5.0e-4, 3.0e-3],
+    appearance: [color: [0.15, 0.18, 0.12], metallic: false, roughness: 0.6, ior: 1.75, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+    thermal: [conductivity_wmk: [2.5, 3.5], specific_heat_jkgk: [750.0, 850.0]],
+    sound_ms: [longitudinal: [7800.0, 8500.0], shear: [4400.0, 4800.0]],
 );
 
 new_elem!( Gold,
@@ -444,6 +2325,41 @@ new_elem!( Gold,
     dencity_gsm3: [19.3, 19.32],
     melting_c: [1064.18, 1064.18],
     metal: GroupMetal::Transition,
+    precious: [PreciousMetal::Gold, 1000.0],
+    resistivity_nohm_m: [22.14, 22.14], tcr_k: [0.0034, 0.0037],
+    magnetic: [MagneticClass::Diamagnetic, -3.44e-5, -3.44e-5],
+    appearance: [color: [1.0, 0.766, 0.336], metallic: true, roughness: 0.2, ior: 0.47, transmission: 0.0],
+    emissivity: [0.02, 0.06],
+    thermal: [conductivity_wmk: [318.0, 318.0], specific_heat_jkgk: [129.0, 129.0]],
+    elastic_gpa: [79.0, 79.0], poisson: [0.42, 0.42],
+);
+new_elem!( Silver,
+    names: [SLang::RU:"Серебро", SLang::EN:"Silver"],
+    element: [47, "Ag"],
+    dencity_gsm3: [10.49, 10.5],
+    melting_c: [961.78, 961.78],
+    metal: GroupMetal::Transition,
+    precious: [PreciousMetal::Silver, 1000.0],
+    resistivity_nohm_m: [15.87, 15.87], tcr_k: [0.0038, 0.0041],
+    magnetic: [MagneticClass::Diamagnetic, -2.38e-5, -2.38e-5],
+    appearance: [color: [0.972, 0.960, 0.915], metallic: true, roughness: 0.15, ior: 0.16, transmission: 0.0],
+    emissivity: [0.02, 0.03],
+    thermal: [conductivity_wmk: [429.0, 429.0], specific_heat_jkgk: [235.0, 235.0]],
+    elastic_gpa: [76.0, 83.0], poisson: [0.37, 0.37],
+);
+new_elem!( Platinum,
+    names: [SLang::RU:"Платина", SLang::EN:"Platinum"],
+    element: [78, "Pt"],
+    dencity_gsm3: [21.45, 21.45],
+    melting_c: [1768.3, 1768.3],
+    metal: GroupMetal::Transition,
+    precious: [PreciousMetal::Platinum, 1000.0],
+    resistivity_nohm_m: [105.0, 106.0], tcr_k: [0.00385, 0.00392],
+    magnetic: [MagneticClass::Paramagnetic, 2.6e-4, 2.8e-4],
+    appearance: [color: [0.672, 0.637, 0.585], metallic: true, roughness: 0.2, ior: 2.33, transmission: 0.0],
+    emissivity: [0.05, 0.1],
+    thermal: [conductivity_wmk: [71.6, 71.6], specific_heat_jkgk: [133.0, 133.0]],
+    elastic_gpa: [168.0, 168.0], poisson: [0.38, 0.38],
 );
 new_elem!( Iron,
     names: [SLang::RU:"Железо", SLang::EN:"Iron"],
@@ -451,6 +2367,12 @@ new_elem!( Iron,
     dencity_gsm3: [7.874, 7.874],
     melting_c: [1538.85, 1538.85],
     metal: GroupMetal::Transition,
+    resistivity_nohm_m: [96.1, 97.1], tcr_k: [0.0050, 0.0065],
+    magnetic: [MagneticClass::Ferromagnetic, 200.0, 5000.0, curie_c: 770.0],
+    appearance: [color: [0.56, 0.57, 0.58], metallic: true, roughness: 0.45, ior: 2.95, transmission: 0.0],
+    emissivity: [0.2, 0.7],
+    thermal: [conductivity_wmk: [80.4, 80.4], specific_heat_jkgk: [449.0, 449.0]],
+    elastic_gpa: [211.0, 211.0], poisson: [0.29, 0.29],
 );
 new_elem!( Aluminium,
     names: [SLang::RU:"Алюминий", SLang::EN:"Aluminium"],
@@ -458,6 +2380,12 @@ new_elem!( Aluminium,
     dencity_gsm3: [2.6989, 2.6989],
     melting_c: [933.5, 933.5],
     metal: GroupMetal::PostTransition,
+    resistivity_nohm_m: [26.5, 28.2], tcr_k: [0.0039, 0.00429],
+    magnetic: [MagneticClass::Paramagnetic, 2.2e-5, 2.2e-5],
+    appearance: [color: [0.913, 0.922, 0.924], metallic: true, roughness: 0.3, ior: 0.96, transmission: 0.0],
+    emissivity: [0.04, 0.1],
+    thermal: [conductivity_wmk: [237.0, 237.0], specific_heat_jkgk: [897.0, 897.0]],
+    elastic_gpa: [68.0, 70.0], poisson: [0.35, 0.35],
 );
 new_elem!( Copper,
     names: [SLang::RU:"Медь", SLang::EN:"Copper"],
@@ -465,6 +2393,12 @@ new_elem!( Copper,
     dencity_gsm3: [8.92, 8.92],
     melting_c: [1083.4, 1083.4],
     metal: GroupMetal::Transition,
+    resistivity_nohm_m: [16.78, 17.24], tcr_k: [0.00386, 0.00404],
+    magnetic: [MagneticClass::Diamagnetic, -9.63e-6, -9.63e-6],
+    appearance: [color: [0.955, 0.638, 0.538], metallic: true, roughness: 0.25, ior: 0.95, transmission: 0.0],
+    emissivity: [0.03, 0.1],
+    thermal: [conductivity_wmk: [401.0, 401.0], specific_heat_jkgk: [385.0, 385.0]],
+    elastic_gpa: [110.0, 130.0], poisson: [0.34, 0.34],
 );
 new_elem!( Zinc,
     names: [SLang::RU:"Цинк", SLang::EN:"Zinc"],
@@ -472,6 +2406,12 @@ new_elem!( Zinc,
     dencity_gsm3: [7.13, 7.13],
     melting_c: [419.55, 419.55],
     metal: GroupMetal::Transition,
+    resistivity_nohm_m: [59.0, 59.6], tcr_k: [0.0037, 0.0042],
+    magnetic: [MagneticClass::Diamagnetic, -1.56e-5, -1.56e-5],
+    appearance: [color: [0.664, 0.824, 0.85], metallic: true, roughness: 0.4, ior: 1.0, transmission: 0.0],
+    emissivity: [0.05, 0.25],
+    thermal: [conductivity_wmk: [116.0, 116.0], specific_heat_jkgk: [388.0, 388.0]],
+    elastic_gpa: [108.0, 108.0], poisson: [0.25, 0.25],
 );
 new_elem!( Magnesium,
     names: [SLang::RU:"Магний", SLang::EN:"Magnesium"],
@@ -479,6 +2419,12 @@ new_elem!( Magnesium,
     dencity_gsm3: [1.738, 1.738],
     melting_c: [650.0, 650.0],
     metal: GroupMetal::AlkalineEarth,
+    resistivity_nohm_m: [43.9, 44.6], tcr_k: [0.0039, 0.0043],
+    magnetic: [MagneticClass::Paramagnetic, 1.2e-5, 1.2e-5],
+    appearance: [color: [0.8, 0.8, 0.78], metallic: true, roughness: 0.4, ior: 0.5, transmission: 0.0],
+    emissivity: [0.07, 0.13],
+    thermal: [conductivity_wmk: [156.0, 156.0], specific_heat_jkgk: [1023.0, 1023.0]],
+    elastic_gpa: [45.0, 45.0], poisson: [0.29, 0.29],
 );
 new_elem!( Manganese,
     names: [SLang::RU:"Марганец", SLang::EN:"Manganese"],
@@ -486,14 +2432,69 @@ new_elem!( Manganese,
     dencity_gsm3: [7.21, 7.21],
     melting_c: [1243.0, 1243.0],
     metal: GroupMetal::Transition,
+    magnetic: [MagneticClass::Paramagnetic, 8.4e-4, 8.7e-4],
+    appearance: [color: [0.58, 0.58, 0.6], metallic: true, roughness: 0.5, ior: 2.4, transmission: 0.0],
+    thermal: [conductivity_wmk: [7.8, 7.8], specific_heat_jkgk: [479.0, 479.0]],
+    elastic_gpa: [198.0, 198.0], poisson: [0.24, 0.24],
 );
 new_elem!( Hydrogen,
     names: [SLang::RU:"Водород", SLang::EN:"Hydrogen"],
     element: [1, "H"],
     dencity_gsm3: [0.0000899, 0.0000899],
-    ignition_c: [510.0, 590.0],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 2.016, critical: [t_k: 33.19, p_kpa: 1296.4], acentric: -0.216],
+    ignition_c: [500.0, 585.0],
     burning_c: [2600.0, 2900.0],
-    heat_value_mj: [141.865, 141.865],
+    heat_value_mj: [hhv: [141.7, 141.9], lhv: [119.9, 120.1], basis: HeatBasis::AsReceived],
+    gas_heat_value: true,
+    ultimate_pct: [c: 0.0, h: 100.0, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 4.0, ufl: 75.0, mie_millijoule: 0.017, autoignition_c: [500.0, 585.0]],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    magnetic: [MagneticClass::Diamagnetic, -2.2e-9, -2.2e-9],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000132, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.1805, 0.1805], specific_heat_jkgk: [14304.0, 14304.0]],
+    sound_ms: [longitudinal: [1270.0, 1270.0], shear: [0.0, 0.0]],
+);
+new_elem!( Nitrogen,
+    names: [SLang::RU:"Азот", SLang::EN:"Nitrogen"],
+    element: [7, "N"],
+    dencity_gsm3: [0.0012506, 0.0012506],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 28.014, critical: [t_k: 126.19, p_kpa: 3395.8], acentric: 0.037],
+    melting_c: [-210.0, -210.0],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000298, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.024, 0.026], specific_heat_jkgk: [1040.0, 1040.0]],
+);
+new_elem!( Oxygen,
+    names: [SLang::RU:"Кислород", SLang::EN:"Oxygen"],
+    element: [8, "O"],
+    dencity_gsm3: [0.001429, 0.001429],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 31.998, critical: [t_k: 154.58, p_kpa: 5043.0], acentric: 0.022],
+    melting_c: [-218.79, -218.79],
+    magnetic: [MagneticClass::Paramagnetic, 1.9e-6, 1.9e-6],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000271, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0244, 0.0266], specific_heat_jkgk: [918.0, 918.0]],
+);
+new_elem!( Helium,
+    names: [SLang::RU:"Гелий", SLang::EN:"Helium"],
+    element: [2, "He"],
+    dencity_gsm3: [0.0001786, 0.0001786],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 4.0026, critical: [t_k: 5.1953, p_kpa: 227.46], acentric: -0.390],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000035, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.1513, 0.1513], specific_heat_jkgk: [5193.0, 5193.0]],
+    sound_ms: [longitudinal: [972.0, 972.0], shear: [0.0, 0.0]],
+);
+new_elem!( Argon,
+    names: [SLang::RU:"Аргон", SLang::EN:"Argon"],
+    element: [18, "Ar"],
+    dencity_gsm3: [0.0017837, 0.0017837],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 39.948, critical: [t_k: 150.69, p_kpa: 4863.0], acentric: -0.002],
+    melting_c: [-189.34, -189.34],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000281, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0164, 0.0177], specific_heat_jkgk: [520.0, 520.0]],
 );
 
 new_alloy!( Brass,
@@ -504,6 +2505,12 @@ new_alloy!( Brass,
     ],
     dencity_gsm3: [8.5, 8.7],
     melting_c: [900.0, 950.0],
+    resistivity_nohm_m: [60.0, 80.0], tcr_k: [0.0015, 0.0020],
+    magnetic: [MagneticClass::Diamagnetic, -1.0e-5, -0.5e-5],
+    appearance: [color: [0.91, 0.778, 0.423], metallic: true, roughness: 0.3, ior: 0.44, transmission: 0.0],
+    emissivity: [0.03, 0.22],
+    thermal: [conductivity_wmk: [109.0, 125.0], specific_heat_jkgk: [375.0, 380.0]],
+    elastic_gpa: [97.0, 110.0], poisson: [0.34, 0.34],
 );
 new_alloy!( Dural,
     names: [SLang::RU:"Дюраль", SLang::EN:"Dural"],
@@ -513,8 +2520,434 @@ new_alloy!( Dural,
         [Magnesium, 1.2, 1.8],
         [Manganese, 0.3, 0.9],
     ],
-    dencity_gsm3: [2.79, 2.77],
+    dencity_gsm3: [2.77, 2.79],
     melting_c: [515.0, 640.0],
+    resistivity_nohm_m: [49.0, 58.0], tcr_k: [0.0020, 0.0025],
+    magnetic: [MagneticClass::Paramagnetic, 1.8e-5, 2.2e-5],
+    appearance: [color: [0.91, 0.92, 0.92], metallic: true, roughness: 0.35, ior: 1.2, transmission: 0.0],
+    emissivity: [0.05, 0.1],
+    thermal: [conductivity_wmk: [130.0, 160.0], specific_heat_jkgk: [875.0, 920.0]],
+    elastic_gpa: [70.0, 74.0], poisson: [0.33, 0.33],
+);
+new_alloy!( Gold750,
+    names: [SLang::RU:"Золото 750 пробы", SLang::EN:"18K gold"],
+    chemical_composition: [
+        [Gold, 75.0, 75.5],
+        [Silver, 4.0, 16.0],
+        [Copper, 9.0, 21.0],
+    ],
+    dencity_gsm3: [15.2, 15.9],
+    melting_c: [880.0, 960.0],
+    precious: [PreciousMetal::Gold, 750.0],
+    appearance: [color: [0.98, 0.78, 0.42], metallic: true, roughness: 0.2, ior: 0.5, transmission: 0.0],
+    emissivity: [0.02, 0.06],
+    thermal: [conductivity_wmk: [50.0, 110.0], specific_heat_jkgk: [150.0, 160.0]],
+);
+new_alloy!( Gold585,
+    names: [SLang::RU:"Золото 585 пробы", SLang::EN:"14K gold"],
+    chemical_composition: [
+        [Gold, 58.5, 59.0],
+        [Silver, 4.0, 30.0],
+        [Copper, 12.0, 37.0],
+    ],
+    dencity_gsm3: [12.9, 14.6],
+    melting_c: [800.0, 900.0],
+    precious: [PreciousMetal::Gold, 585.0],
+    appearance: [color: [0.95, 0.72, 0.45], metallic: true, roughness: 0.2, ior: 0.6, transmission: 0.0],
+    emissivity: [0.02, 0.06],
+    thermal: [conductivity_wmk: [40.0, 100.0], specific_heat_jkgk: [170.0, 190.0]],
+);
+new_alloy!( SterlingSilver,
+    names: [SLang::RU:"Серебро 925 пробы", SLang::EN:"Sterling silver"],
+    chemical_composition: [
+        [Silver, 92.5, 93.0],
+        [Copper, 7.0, 7.5],
+    ],
+    dencity_gsm3: [10.3, 10.4],
+    melting_c: [802.0, 893.0],
+    precious: [PreciousMetal::Silver, 925.0],
+    resistivity_nohm_m: [19.0, 21.0], tcr_k: [0.0035, 0.0038],
+    appearance: [color: [0.96, 0.94, 0.9], metallic: true, roughness: 0.2, ior: 0.18, transmission: 0.0],
+    emissivity: [0.02, 0.05],
+    thermal: [conductivity_wmk: [360.0, 380.0], specific_heat_jkgk: [235.0, 245.0]],
+);
+new_alloy!( Platinum950,
+    names: [SLang::RU:"Платина 950 пробы", SLang::EN:"Platinum 950"],
+    chemical_composition: [
+        [Platinum, 95.0, 95.5],
+        [Copper, 4.5, 5.0],
+    ],
+    dencity_gsm3: [20.0, 20.7],
+    melting_c: [1730.0, 1745.0],
+    precious: [PreciousMetal::Platinum, 950.0],
+    appearance: [color: [0.68, 0.65, 0.6], metallic: true, roughness: 0.2, ior: 2.3, transmission: 0.0],
+    emissivity: [0.05, 0.1],
+    thermal: [conductivity_wmk: [40.0, 72.0], specific_heat_jkgk: [130.0, 140.0]],
+);
+
+new_fuel!( Oak,
+    names: [SLang::RU:"Дуб", SLang::EN:"Oak"],
+    fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.6]],
+    dencity_gsm3: [0.65, 0.85],
+    ignition_c: [250.0, 300.0],
+    burning_c: [900.0, 1100.0],
+    heat_value_mj: [hhv: [19.5, 20.0], lhv: [18.2, 18.7], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 50.5, h: 6.0, o: 43.1, n: 0.3, s: 0.1, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.3, 0.18, 0.08], metallic: false, roughness: 0.7, ior: 1.5, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+    thermal: [conductivity_wmk: [0.16, 0.18], specific_heat_jkgk: [1700.0, 2400.0]],
+);
+new_fuel!( Birch,
+    names: [SLang::RU:"Берёза", SLang::EN:"Birch"],
+    fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.5]],
+    dencity_gsm3: [0.6, 0.7],
+    ignition_c: [250.0, 300.0],
+    burning_c: [900.0, 1100.0],
+    heat_value_mj: [hhv: [19.6, 20.2], lhv: [18.3, 18.9], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 50.2, h: 6.1, o: 43.3, n: 0.3, s: 0.1, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.6, 0.48, 0.3], metallic: false, roughness: 0.7, ior: 1.5, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+    thermal: [conductivity_wmk: [0.14, 0.16], specific_heat_jkgk: [1700.0, 2400.0]],
+);
+new_fuel!( Pine,
+    names: [SLang::RU:"Сосна", SLang::EN:"Pine"],
+    fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.2, 0.5]],
+    dencity_gsm3: [0.45, 0.55],
+    ignition_c: [250.0, 300.0],
+    burning_c: [900.0, 1100.0],
+    heat_value_mj: [hhv: [20.0, 21.0], lhv: [18.7, 19.7], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 51.5, h: 6.2, o: 42.1, n: 0.1, s: 0.1, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.55, 0.38, 0.18], metallic: false, roughness: 0.7, ior: 1.5, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+    thermal: [conductivity_wmk: [0.12, 0.14], specific_heat_jkgk: [1700.0, 2400.0]],
+);
+new_fuel!( Spruce,
+    names: [SLang::RU:"Ель", SLang::EN:"Spruce"],
+    fuel: [GroupFuel::Wood, moisture: [15.0, 25.0], ash: [0.3, 0.6]],
+    dencity_gsm3: [0.4, 0.5],
+    ignition_c: [250.0, 300.0],
+    burning_c: [900.0, 1100.0],
+    heat_value_mj: [hhv: [19.8, 20.5], lhv: [18.5, 19.2], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 51.0, h: 6.1, o: 42.7, n: 0.1, s: 0.1, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.6, 0.47, 0.3], metallic: false, roughness: 0.7, ior: 1.5, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+    thermal: [conductivity_wmk: [0.11, 0.13], specific_heat_jkgk: [1700.0, 2400.0]],
+);
+new_fuel!( Peat,
+    names: [SLang::RU:"Торф", SLang::EN:"Peat"],
+    fuel: [GroupFuel::Peat, moisture: [35.0, 50.0], ash: [3.0, 8.0]],
+    dencity_gsm3: [0.4, 0.8],
+    ignition_c: [225.0, 280.0],
+    burning_c: [1000.0, 1100.0],
+    heat_value_mj: [hhv: [22.0, 23.0], lhv: [20.8, 21.8], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 57.0, h: 5.6, o: 34.5, n: 2.5, s: 0.4, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.06, 0.04, 0.025], metallic: false, roughness: 0.95, ior: 1.5, transmission: 0.0],
+    emissivity: [0.9, 0.95],
+    thermal: [conductivity_wmk: [0.07, 0.12], specific_heat_jkgk: [1800.0, 2100.0]],
+);
+new_fuel!( Charcoal,
+    names: [SLang::RU:"Древесный уголь", SLang::EN:"Charcoal"],
+    fuel: [GroupFuel::Charcoal, moisture: [3.0, 7.0], ash: [2.0, 5.0]],
+    dencity_gsm3: [0.3, 0.5],
+    ignition_c: [350.0, 400.0],
+    burning_c: [1100.0, 1300.0],
+    heat_value_mj: [hhv: [33.0, 34.5], lhv: [32.5, 34.0], basis: HeatBasis::DryAshFree],
+    ultimate_pct: [c: 92.0, h: 2.5, o: 5.0, n: 0.4, s: 0.1, moisture: 0.0, ash: 0.0],
+    appearance: [color: [0.02, 0.02, 0.02], metallic: false, roughness: 0.9, ior: 1.8, transmission: 0.0],
+    emissivity: [0.9, 0.96],
+    thermal: [conductivity_wmk: [0.05, 0.09], specific_heat_jkgk: [840.0, 1000.0]],
+);
+new_fuel!( Gasoline,
+    names: [SLang::RU:"Бензин", SLang::EN:"Gasoline"],
+    fuel: [GroupFuel::Liquid, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.72, 0.775],
+    burning_c: [2000.0, 2100.0],
+    heat_value_mj: [hhv: [46.0, 47.0], lhv: [42.9, 43.9], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 85.5, h: 14.5, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 1.4, ufl: 7.6, mie_millijoule: 0.2, autoignition_c: [246.0, 280.0], flash_point_c: -43.0],
+    appearance: [color: [0.95, 0.9, 0.7], metallic: false, roughness: 0.0, ior: 1.42, transmission: 0.95],
+    thermal: [conductivity_wmk: [0.11, 0.13], specific_heat_jkgk: [2000.0, 2220.0]],
+    boiling_c: [35.0, 200.0],
+    liquid: [viscosity_vogel: [a_pa_s: 8.84e-6, b_k: 513.8, c_k: 6.76], surface_tension_mn_m: [21.0, at_c: 20.0, per_k: -0.09]],
+);
+new_fuel!( Diesel,
+    names: [SLang::RU:"Дизельное топливо", SLang::EN:"Diesel"],
+    fuel: [GroupFuel::Liquid, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.82, 0.845],
+    burning_c: [2000.0, 2100.0],
+    heat_value_mj: [hhv: [45.4, 45.8], lhv: [42.6, 43.0], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 86.5, h: 13.5, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 0.6, ufl: 7.5, mie_millijoule: 0.23, autoignition_c: [210.0, 256.0], flash_point_c: 55.0],
+    appearance: [color: [0.9, 0.8, 0.45], metallic: false, roughness: 0.0, ior: 1.46, transmission: 0.9],
+    thermal: [conductivity_wmk: [0.13, 0.15], specific_heat_jkgk: [1800.0, 2050.0]],
+    boiling_c: [180.0, 360.0],
+    liquid: [viscosity_vogel: [a_pa_s: 9.64e-5, b_k: 224.1, c_k: 154.6], surface_tension_mn_m: [28.0, at_c: 20.0, per_k: -0.08]],
+);
+new_fuel!( Kerosene,
+    names: [SLang::RU:"Керосин", SLang::EN:"Kerosene"],
+    fuel: [GroupFuel::Liquid, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.78, 0.81],
+    burning_c: [2000.0, 2100.0],
+    heat_value_mj: [hhv: [46.0, 46.4], lhv: [42.9, 43.3], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 86.0, h: 14.0, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 0.7, ufl: 5.0, mie_millijoule: 0.25, autoignition_c: [210.0, 220.0], flash_point_c: 38.0],
+    appearance: [color: [1.0, 1.0, 0.95], metallic: false, roughness: 0.0, ior: 1.44, transmission: 0.95],
+    thermal: [conductivity_wmk: [0.14, 0.15], specific_heat_jkgk: [2000.0, 2100.0]],
+    boiling_c: [150.0, 300.0],
+    liquid: [viscosity_vogel: [a_pa_s: 1.409e-5, b_k: 604.4, c_k: 0.0], surface_tension_mn_m: [26.0, at_c: 20.0, per_k: -0.085]],
+);
+new_fuel!( Ethanol,
+    names: [SLang::RU:"Этанол", SLang::EN:"Ethanol"],
+    fuel: [GroupFuel::Liquid, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.789, 0.789],
+    burning_c: [1900.0, 1950.0],
+    heat_value_mj: [hhv: [29.7, 29.7], lhv: [26.8, 26.8], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 52.14, h: 13.13, o: 34.73, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 3.3, ufl: 19.0, mie_millijoule: 0.65, autoignition_c: [363.0, 363.0], flash_point_c: 13.0],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.361, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.167, 0.171], specific_heat_jkgk: [2400.0, 2460.0]],
+    boiling_c: [78.37, 78.37],
+    liquid: [viscosity_vogel: [a_pa_s: 4.53e-6, b_k: 708.7, c_k: 0.0], surface_tension_mn_m: [22.3, at_c: 20.0, per_k: -0.0832]],
+    antoine_mmhg_c: [8.20417, 1642.89, 230.3],
+);
+new_fuel!( Methane,
+    names: [SLang::RU:"Метан", SLang::EN:"Methane"],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.000717, 0.000717],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 16.043, critical: [t_k: 190.56, p_kpa: 4599.2], acentric: 0.011],
+    burning_c: [1950.0, 1960.0],
+    heat_value_mj: [hhv: [55.5, 55.5], lhv: [50.0, 50.0], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 74.87, h: 25.13, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 5.0, ufl: 15.0, mie_millijoule: 0.28, autoignition_c: [537.0, 580.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000444, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.034, 0.034], specific_heat_jkgk: [2220.0, 2220.0]],
+);
+new_fuel!( Propane,
+    names: [SLang::RU:"Пропан", SLang::EN:"Propane"],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.00201, 0.00201],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 44.097, critical: [t_k: 369.83, p_kpa: 4248.0], acentric: 0.152],
+    burning_c: [1980.0, 1995.0],
+    heat_value_mj: [hhv: [50.35, 50.35], lhv: [46.35, 46.35], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 81.71, h: 18.29, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 2.1, ufl: 9.5, mie_millijoule: 0.25, autoignition_c: [450.0, 470.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.001, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.018, 0.018], specific_heat_jkgk: [1670.0, 1670.0]],
+);
+new_fuel!( Butane,
+    names: [SLang::RU:"Бутан", SLang::EN:"Butane"],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.0027, 0.0027],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 58.123, critical: [t_k: 425.12, p_kpa: 3796.0], acentric: 0.2],
+    burning_c: [1970.0, 1990.0],
+    heat_value_mj: [hhv: [49.5, 49.5], lhv: [45.7, 45.7], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 82.66, h: 17.34, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 1.8, ufl: 8.4, mie_millijoule: 0.25, autoignition_c: [365.0, 405.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.0013, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.016, 0.016], specific_heat_jkgk: [1700.0, 1700.0]],
+);
+new_fuel!( Ethane,
+    names: [SLang::RU:"Этан", SLang::EN:"Ethane"],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.001356, 0.001356],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 30.069, critical: [t_k: 305.32, p_kpa: 4872.0], acentric: 0.099],
+    burning_c: [1955.0, 1960.0],
+    heat_value_mj: [hhv: [51.9, 51.9], lhv: [47.5, 47.5], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 79.89, h: 20.11, o: 0.0, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 3.0, ufl: 12.4, mie_millijoule: 0.24, autoignition_c: [472.0, 515.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00076, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0184, 0.0184], specific_heat_jkgk: [1750.0, 1750.0]],
+);
+new_fuel!( CarbonMonoxide,
+    names: [SLang::RU:"Угарный газ", SLang::EN:"Carbon Monoxide"],
+    fuel: [GroupFuel::Gas, moisture: [0.0, 0.0], ash: [0.0, 0.0]],
+    dencity_gsm3: [0.00125, 0.00125],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 28.01, critical: [t_k: 132.86, p_kpa: 3494.0], acentric: 0.045],
+    burning_c: [2100.0, 2120.0],
+    heat_value_mj: [hhv: [10.1, 10.1], lhv: [10.1, 10.1], basis: HeatBasis::AsReceived],
+    ultimate_pct: [c: 42.88, h: 0.0, o: 57.12, n: 0.0, s: 0.0, moisture: 0.0, ash: 0.0],
+    flammability: [lfl: 12.5, ufl: 74.0, autoignition_c: [605.0, 609.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000338, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0232, 0.0232], specific_heat_jkgk: [1040.0, 1040.0]],
+);
+
+new_liquid!( Water,
+    names: [SLang::RU:"Вода", SLang::EN:"Water"],
+    dencity_gsm3: [0.9982, 0.9982],
+    melting_c: [0.0, 0.0],
+    boiling_c: [100.0, 100.0],
+    liquid: [viscosity_vogel: [a_pa_s: 2.414e-5, b_k: 247.8, c_k: 140.0], surface_tension_mn_m: [72.8, at_c: 20.0, per_k: -0.168]],
+    antoine_mmhg_c: [8.07131, 1730.63, 233.426],
+    latent_heat_kj_kg: [fusion: 333.55, vaporization: 2256.4],
+    appearance: [color: [0.95, 0.98, 1.0], metallic: false, roughness: 0.0, ior: 1.333, transmission: 1.0],
+    emissivity: [0.95, 0.96],
+    thermal: [conductivity_wmk: [0.598, 0.598], specific_heat_jkgk: [4182.0, 4182.0]],
+);
+new_liquid!( SeaWater,
+    names: [SLang::RU:"Морская вода", SLang::EN:"Sea Water"],
+    dencity_gsm3: [1.02, 1.029],
+    melting_c: [-1.9, -1.9],
+    boiling_c: [100.6, 100.6],
+    liquid: [viscosity_vogel: [a_pa_s: 2.6e-5, b_k: 247.8, c_k: 140.0], surface_tension_mn_m: [73.8, at_c: 20.0, per_k: -0.16]],
+    appearance: [color: [0.75, 0.9, 0.95], metallic: false, roughness: 0.0, ior: 1.339, transmission: 0.95],
+    emissivity: [0.95, 0.96],
+    thermal: [conductivity_wmk: [0.596, 0.596], specific_heat_jkgk: [3993.0, 3993.0]],
+);
+new_liquid!( EngineOil,
+    names: [SLang::RU:"Моторное масло", SLang::EN:"Engine Oil"],
+    dencity_gsm3: [0.86, 0.89],
+    liquid: [viscosity_vogel: [a_pa_s: 2.89e-5, b_k: 536.5, c_k: 159.6], surface_tension_mn_m: [31.0, at_c: 20.0, per_k: -0.06]],
+    appearance: [color: [0.55, 0.35, 0.08], metallic: false, roughness: 0.0, ior: 1.47, transmission: 0.6],
+    emissivity: [0.8, 0.85],
+    thermal: [conductivity_wmk: [0.14, 0.15], specific_heat_jkgk: [1880.0, 2000.0]],
+);
+new_liquid!( OliveOil,
+    names: [SLang::RU:"Оливковое масло", SLang::EN:"Olive Oil"],
+    dencity_gsm3: [0.91, 0.92],
+    melting_c: [-6.0, 0.0],
+    liquid: [viscosity_vogel: [a_pa_s: 1.44e-5, b_k: 695.0, c_k: 108.5], surface_tension_mn_m: [32.0, at_c: 20.0, per_k: -0.067]],
+    appearance: [color: [0.6, 0.6, 0.1], metallic: false, roughness: 0.0, ior: 1.47, transmission: 0.8],
+    thermal: [conductivity_wmk: [0.17, 0.17], specific_heat_jkgk: [1970.0, 1970.0]],
+);
+new_liquid!( Mercury,
+    names: [SLang::RU:"Ртуть", SLang::EN:"Mercury"],
+    dencity_gsm3: [13.546, 13.546],
+    melting_c: [-38.83, -38.83],
+    boiling_c: [356.73, 356.73],
+    liquid: [viscosity_vogel: [a_pa_s: 6.69e-4, b_k: 80.7, c_k: 71.9], surface_tension_mn_m: [485.5, at_c: 20.0, per_k: -0.2]],
+    latent_heat_kj_kg: [fusion: 11.4, vaporization: 294.7],
+    appearance: [color: [0.78, 0.78, 0.78], metallic: true, roughness: 0.05, ior: 1.6, transmission: 0.0],
+    emissivity: [0.1, 0.12],
+    thermal: [conductivity_wmk: [8.3, 8.3], specific_heat_jkgk: [139.0, 139.0]],
+);
+new_liquid!( MoltenIron,
+    names: [SLang::RU:"Расплав железа", SLang::EN:"Molten Iron"],
+    dencity_gsm3: [6.98, 6.98],
+    dencity_at: ReferenceConditions {temperature: 1538.0, pressure: 101.325},
+    melting_c: [1538.0, 1538.0],
+    boiling_c: [2861.0, 2861.0],
+    liquid: [viscosity_vogel: [a_pa_s: 3.699e-4, b_k: 2162.6, c_k: 0.0], surface_tension_mn_m: [1872.0, at_c: 1538.0, per_k: -0.49]],
+    latent_heat_kj_kg: [fusion: 247.0, vaporization: 6090.0],
+    appearance: [color: [0.56, 0.57, 0.58], metallic: true, roughness: 0.1, ior: 2.95, transmission: 0.0],
+    emissivity: [0.35, 0.45],
+    thermal: [conductivity_wmk: [35.0, 40.0], specific_heat_jkgk: [820.0, 835.0]],
+);
+new_liquid!( MoltenAluminium,
+    names: [SLang::RU:"Расплав алюминия", SLang::EN:"Molten Aluminium"],
+    dencity_gsm3: [2.375, 2.375],
+    dencity_at: ReferenceConditions {temperature: 660.32, pressure: 101.325},
+    melting_c: [660.32, 660.32],
+    boiling_c: [2470.0, 2470.0],
+    liquid: [viscosity_vogel: [a_pa_s: 2.789e-4, b_k: 558.9, c_k: 0.0], surface_tension_mn_m: [871.0, at_c: 660.32, per_k: -0.155]],
+    latent_heat_kj_kg: [fusion: 397.0, vaporization: 10900.0],
+    appearance: [color: [0.91, 0.92, 0.92], metallic: true, roughness: 0.05, ior: 1.44, transmission: 0.0],
+    emissivity: [0.1, 0.2],
+    thermal: [conductivity_wmk: [90.0, 95.0], specific_heat_jkgk: [1180.0, 1180.0]],
+);
+
+new_compound!( Quartz,
+    names: [SLang::RU:"Кварц", SLang::EN:"Quartz"],
+    formula: "SiO2",
+    dencity_gsm3: [2.65, 2.66],
+    melting_c: [1670.0, 1713.0],
+    appearance: [color: [0.95, 0.95, 0.93], metallic: false, roughness: 0.2, ior: 1.544, transmission: 0.8],
+    emissivity: [0.8, 0.93],
+    thermal: [conductivity_wmk: [6.0, 10.0], specific_heat_jkgk: [730.0, 750.0]],
+);
+new_compound!( Calcite,
+    names: [SLang::RU:"Кальцит", SLang::EN:"Calcite"],
+    formula: "CaCO3",
+    dencity_gsm3: [2.71, 2.71],
+    appearance: [color: [0.95, 0.94, 0.9], metallic: false, roughness: 0.3, ior: 1.6, transmission: 0.5],
+    emissivity: [0.9, 0.95],
+    thermal: [conductivity_wmk: [3.2, 3.6], specific_heat_jkgk: [820.0, 840.0]],
+);
+new_compound!( CementStone,
+    names: [SLang::RU:"Цементный камень", SLang::EN:"Hardened cement paste"],
+    // calcium silicate hydrate in cement chemist notation
+    formula: "C-S-H",
+    dencity_gsm3: [2.3, 2.6],
+    appearance: [color: [0.62, 0.62, 0.6], metallic: false, roughness: 0.9, ior: 1.5, transmission: 0.0],
+    emissivity: [0.9, 0.95],
+    thermal: [conductivity_wmk: [0.9, 1.3], specific_heat_jkgk: [900.0, 1000.0]],
+);
+new_compound!( Ice,
+    names: [SLang::RU:"Лёд", SLang::EN:"Ice"],
+    formula: "H2O",
+    dencity_gsm3: [0.9167, 0.9167],
+    dencity_at: ReferenceConditions {temperature: 0.0, pressure: 101.325},
+    melting_c: [0.0, 0.0],
+    latent_heat_kj_kg: [fusion: 333.55, sublimation: 2834.0],
+    appearance: [color: [0.9, 0.95, 1.0], metallic: false, roughness: 0.1, ior: 1.31, transmission: 0.9],
+    emissivity: [0.96, 0.98],
+    thermal: [conductivity_wmk: [2.22, 2.22], specific_heat_jkgk: [2050.0, 2050.0]],
+);
+new_compound!( Steam,
+    names: [SLang::RU:"Водяной пар", SLang::EN:"Steam"],
+    formula: "H2O",
+    dencity_gsm3: [0.0005977, 0.0005977],
+    dencity_at: ReferenceConditions {temperature: 100.0, pressure: 101.325},
+    gas: [molar_mass_g_mol: 18.015, critical: [t_k: 647.096, p_kpa: 22064.0], acentric: 0.344],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000256, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0248, 0.0248], specific_heat_jkgk: [2080.0, 2080.0]],
+);
+new_compound!( CarbonDioxide,
+    names: [SLang::RU:"Углекислый газ", SLang::EN:"Carbon Dioxide"],
+    formula: "CO2",
+    dencity_gsm3: [0.0019768, 0.0019768],
+    dencity_at: ReferenceConditions::STP,
+    gas: [molar_mass_g_mol: 44.009, critical: [t_k: 304.13, p_kpa: 7377.3], acentric: 0.224],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00045, transmission: 1.0],
+    thermal: [conductivity_wmk: [0.0146, 0.0166], specific_heat_jkgk: [820.0, 850.0]],
+);
+
+new_mixture!( Air,
+    names: [SLang::RU:"Воздух", SLang::EN:"Air"],
+    mole_pct: [[Nitrogen, 78.084], [Oxygen, 20.946], [Argon, 0.934], [CarbonDioxide, 0.036]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.000293, transmission: 1.0],
+);
+new_mixture!( NaturalGas,
+    names: [SLang::RU:"Природный газ", SLang::EN:"Natural Gas"],
+    mole_pct: [[Methane, 94.0], [Ethane, 3.0], [Propane, 0.5], [Butane, 0.2], [Nitrogen, 1.5], [CarbonDioxide, 0.8]],
+    heat_value: HeatBasis::AsReceived,
+    flammability: [autoignition_c: [580.0, 640.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00044, transmission: 1.0],
+);
+new_mixture!( Syngas,
+    names: [SLang::RU:"Синтез-газ", SLang::EN:"Syngas"],
+    mole_pct: [[CarbonMonoxide, 45.0], [Hydrogen, 30.0], [CarbonDioxide, 10.0], [Methane, 5.0], [Nitrogen, 10.0]],
+    heat_value: HeatBasis::AsReceived,
+    flammability: [autoignition_c: [600.0, 620.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00025, transmission: 1.0],
+);
+new_mixture!( FlueGas,
+    names: [SLang::RU:"Дымовые газы (сухие)", SLang::EN:"Flue Gas (dry)"],
+    mole_pct: [[Nitrogen, 87.5], [CarbonDioxide, 10.5], [Oxygen, 2.0]],
+    appearance: [color: [1.0, 1.0, 1.0], metallic: false, roughness: 0.0, ior: 1.00031, transmission: 1.0],
+);
+
+new_composite!( Concrete,
+    names: [SLang::RU:"Бетон", SLang::EN:"Concrete"],
+    volume_pct: [[Granite, 45.0], [Quartz, 30.0], [CementStone, 25.0]],
+    porosity_pct: 10.0,
+    pores: [Water, saturation_pct: 50.0],
+    appearance: [color: [0.6, 0.6, 0.58], metallic: false, roughness: 0.9, ior: 1.5, transmission: 0.0],
+    emissivity: [0.85, 0.95],
+);
+new_composite!( Sandstone,
+    names: [SLang::RU:"Песчаник", SLang::EN:"Sandstone"],
+    volume_pct: [[Quartz, 90.0], [Calcite, 10.0]],
+    porosity_pct: 20.0,
+    pores: [Water, saturation_pct: 30.0],
+    group: [Sedimentary, GroupRock::Sedimentary, SubgroupSedimentary, SubgroupSedimentary::Clastic],
+    appearance: [color: [0.76, 0.62, 0.45], metallic: false, roughness: 0.85, ior: 1.54, transmission: 0.0],
+    emissivity: [0.8, 0.9],
+    sound_ms: [longitudinal: [2000.0, 4500.0], shear: [1200.0, 2800.0]],
 );
 
 #[cfg(test)]
@@ -571,4 +3004,288 @@ mod tests {
             Hydrogen.get_burning_avg_c(),
         );
     }
-}
\ No newline at end of file
+    #[test]
+    fn electrical() {
+        let iacs = Copper.get_conductivity_iacs();
+        assert!(iacs > 95.0 && iacs < 105.0, "Copper IACS: {}", iacs);
+        assert!(Aluminium.get_conductivity_iacs() < iacs);
+        assert!(Brass.get_conductivity_iacs() < Aluminium.get_conductivity_iacs());
+        // 100 m of 1.5 mm² copper wire ≈ 1.13 Ω at 20 °C
+        let r20 = Copper.get_resistance_ohm(100.0, 1.5, 20.0);
+        assert!((r20 - 1.134).abs() < 0.02, "Copper 100m 1.5mm²: {}Ω", r20);
+        assert!(Copper.get_resistance_ohm(100.0, 1.5, 70.0) > r20);
+        let drop = Copper.get_voltage_drop_v(10.0, 100.0, 1.5, 20.0);
+        assert!((drop - r20 * 10.0).abs() < 1e-4);
+        // silver conducts best of all
+        let best = ALL_MATERIALS
+            .iter()
+            .filter(|material| material.as_electrical().is_some())
+            .max_by(|a, b| a.as_electrical().unwrap().get_conductivity_iacs().total_cmp(&b.as_electrical().unwrap().get_conductivity_iacs()));
+        assert_eq!(best.map(|material| material.get_name(SLang::EN)), Some("Silver"));
+        assert!(Granite.as_electrical().is_none());
+    }
+    #[test]
+    fn magnetic() {
+        assert_eq!(Iron.get_magnetic_class(), MagneticClass::Ferromagnetic);
+        assert_eq!(Aluminium.get_magnetic_class(), MagneticClass::Paramagnetic);
+        assert_eq!(Copper.get_magnetic_class(), MagneticClass::Diamagnetic);
+        assert_eq!(Iron.get_curie_k(), Some(770.0 + 273.15));
+        assert_eq!(Copper.get_curie_c(), None);
+        let ferromagnetic = get_materials_by_magnetic_class(MagneticClass::Ferromagnetic);
+        assert_eq!(ferromagnetic.len(), 1);
+        assert_eq!(ferromagnetic[0].get_name(SLang::EN), "Iron");
+        let diamagnetic = get_materials_by_magnetic_class(MagneticClass::Diamagnetic);
+        assert!(diamagnetic.iter().any(|material| material.get_name(SLang::EN) == "Copper"));
+    }
+    #[test]
+    fn appearance() {
+        let gold = Gold.get_pbr();
+        assert_eq!(gold.metallic, 1.0);
+        assert!(gold.base_color[0] > gold.base_color[2]);
+        for material in ALL_MATERIALS {
+            let pbr = get_pbr(*material).unwrap_or_else(|| panic!("No appearance: {}", material.get_name(SLang::EN)));
+            assert!(pbr.base_color.iter().all(|c| (0.0..=1.0).contains(c)));
+            assert!((0.0..=1.0).contains(&pbr.roughness));
+            assert!((0.0..=1.0).contains(&pbr.get_opacity()));
+        }
+        assert_eq!(get_pbr(&Hydrogen).map(|pbr| pbr.get_opacity()), Some(0.0));
+    }
+    #[test]
+    fn glow() {
+        // molten iron glows orange-yellow
+        let iron = Iron.get_glow_color(Iron.get_melting_avg_k());
+        assert!(iron[0] == 1.0 && iron[1] > iron[2], "Iron: {:?}", iron);
+        // hotter is whiter
+        let coal = BrownCoal.get_glow_color(BrownCoal.get_burning_avg_k());
+        assert!(coal[2] > iron[2], "BrownCoal: {:?}", coal);
+        let daylight = get_incandescent_color(6500.0);
+        assert!(daylight.iter().all(|c| *c > 0.85), "6500K: {:?}", daylight);
+        assert_eq!(get_incandescent_color(0.0), [0.0, 0.0, 0.0]);
+        // ε·σ·T⁴ of burning brown coal is around 1 MW/m²
+        let power = BrownCoal.get_radiated_power_wm2(BrownCoal.get_burning_avg_k());
+        assert!(power > 8.0e5 && power < 1.5e6, "BrownCoal: {}W/m²", power);
+    }
+    #[test]
+    fn acoustic() {
+        // derived from moduli: ~6420 m/s and ~3040 m/s in aluminium
+        let (l, t) = (Aluminium.get_sound_longitudinal_ms(), Aluminium.get_sound_shear_ms());
+        assert!((6300.0..6600.0).contains(&l), "Aluminium: {}m/s", l);
+        assert!((3000.0..3200.0).contains(&t), "Aluminium: {}m/s", t);
+        assert!((3100.0..3400.0).contains(&Gold.get_sound_longitudinal_ms()));
+        assert_eq!(Basalt.get_sound_longitudinal_ms(), 5700.0);
+        assert_eq!(get_reflection_coefficient(&Copper, &Copper), 0.0);
+        let r = get_reflection_coefficient(&Aluminium, &Iron);
+        assert!(r > 0.0 && r < 1.0);
+        assert!((get_transmission_coefficient(&Aluminium, &Iron) - (1.0 + r)).abs() < 1e-6);
+        // almost everything is reflected at a metal-gas boundary
+        assert!(get_reflection_coefficient(&Iron, &Hydrogen) < -0.99);
+        // measured or derived, every material with sound data has a positive impedance
+        let acoustic: Vec<&dyn Acoustic> = ALL_MATERIALS.iter().filter_map(|material| material.as_acoustic()).collect();
+        assert!(acoustic.len() > 10);
+        assert!(acoustic.iter().all(|material| material.get_acoustic_impedance_rayl() > 0.0));
+        assert!(Aluminium.as_elastic().is_some() && Basalt.as_elastic().is_none());
+        assert!(Basalt.as_acoustic().is_some() && Sandstone.as_acoustic().is_some());
+        assert!(Oak.as_acoustic().is_none());
+    }
+    #[test]
+    fn seismic() {
+        assert!((Basalt.get_vp_vs_ratio() - 1.84).abs() < 0.05);
+        let ranked = rank_rocks_by_seismic(8100.0, 4600.0, 3.5, None);
+        assert_eq!(ranked[0].rock.get_name(SLang::EN), "Eclogite");
+        assert!(ranked[0].probability > 0.99);
+        // porous sandstone is a composite rock
+        let ranked = rank_rocks_by_seismic(3200.0, 2000.0, Sandstone.get_density_avg_gcm3(), Some(GroupRock::Sedimentary));
+        assert_eq!(ranked[0].rock.get_name(SLang::EN), "Sandstone");
+        assert!(matches!(Sandstone.get_subgroup(), SubgroupSedimentary::Clastic));
+        let ranked = rank_rocks_by_seismic(2000.0, 950.0, 1.35, None);
+        assert_eq!(ranked[0].rock.get_name(SLang::EN), "Brown Coal");
+        let igneous = rank_rocks_by_seismic(8100.0, 4600.0, 3.5, Some(GroupRock::Igneous));
+        assert!(igneous.iter().all(|candidate| candidate.rock.get_name(SLang::EN) != "Eclogite"));
+        let total: f32 = igneous.iter().map(|candidate| candidate.probability).sum();
+        assert!((total - 1.0).abs() < 1e-4 || total == 0.0);
+    }
+    #[test]
+    fn heat_value() {
+        assert!((Hydrogen.get_hhv_avg_mj() / Hydrogen.get_lhv_avg_mj() - 1.18).abs() < 0.01);
+        assert!((Hydrogen.get_lhv_avg_kkal() - 28_660.0).abs() < 50.0);
+        assert!((Hydrogen.get_lhv_avg_btu_lb() - 51_590.0).abs() < 50.0);
+        // ~12.7 MJ/m³ at 0 °C
+        assert!((Hydrogen.get_hhv_avg_mj_m3() - 12.75).abs() < 0.05);
+        // natural gas ~36 MJ/m³, volumetric values are only for gases
+        assert!((33.0..38.0).contains(&NaturalGas.get_lhv_avg_mj_m3()), "{}MJ/m³", NaturalGas.get_lhv_avg_mj_m3());
+        let gas_fuels: Vec<&dyn GasHeatValue> = ALL_MATERIALS.iter().filter_map(|material| material.as_gas_heat_value()).collect();
+        assert_eq!(gas_fuels.len(), 8);
+        assert!(ALL_MATERIALS
+            .iter()
+            .all(|material| material.as_gas_heat_value().is_some() == (material.as_gas().is_some() && material.as_heat_value().is_some())));
+        assert!(BrownCoal.as_gas_heat_value().is_none());
+        assert_eq!(BrownCoal.get_heat_value_basis(), HeatBasis::AsReceived);
+        // 30 % moisture, 10 % ash: 15 MJ/kg as received is 25 MJ/kg dry ash free
+        let daf = HeatBasis::AsReceived.convert_hhv(15.0, HeatBasis::DryAshFree, 30.0, 10.0);
+        assert!((daf - 25.0).abs() < 1e-3);
+        assert!((HeatBasis::DryAshFree.convert_hhv(daf, HeatBasis::Dry, 30.0, 10.0) - 15.0 / 0.7).abs() < 1e-3);
+        // pure hydrogen
+        assert!((get_lhv_from_hhv(141.8, 100.0, 0.0) - 120.0).abs() < 0.1);
+    }
+    #[test]
+    fn flammability() {
+        assert!(Hydrogen.is_flammable(30.0));
+        assert!(!Hydrogen.is_flammable(2.0));
+        assert!(!Hydrogen.is_flammable(80.0));
+        assert_eq!(Hydrogen.get_flash_point_c(), None);
+        let hydrogen: &dyn Material = &Hydrogen;
+        assert!(hydrogen.as_flammability().is_some_and(|f| f.is_flammable(4.0)));
+        assert!(Gold.as_flammability().is_none());
+        // a gas ignites by itself at its autoignition temperature
+        for material in ALL_MATERIALS {
+            if let (Some(ignition), Some(flammability)) = (material.as_ignition(), material.as_flammability()) {
+                assert_eq!(ignition.get_ignition_avg_c(), flammability.get_autoignition_avg_c(), "{}", material.get_name(SLang::EN));
+            }
+        }
+    }
+    #[test]
+    fn fuel() {
+        // wet wood burns worse per kg but is denser
+        assert!(Oak.get_lhv_mj_at(40.0, 0.5) < Oak.get_lhv_mj_at(15.0, 0.5));
+        assert!(Oak.get_density_kgm3_at(40.0) > Oak.get_density_avg_kgm3());
+        let lhv = Birch.get_lhv_mj_at(20.0, 0.4);
+        assert!((14.0..15.5).contains(&lhv), "Birch: {}MJ/kg", lhv);
+        // tabulated basis round-trips
+        let coal = BrownCoal.get_ultimate_analysis();
+        assert!((BrownCoal.get_hhv_mj_at(coal.moisture, coal.ash) - BrownCoal.get_hhv_avg_mj()).abs() < 1e-3);
+        assert!(BrownCoal.get_burning_c_at(50.0, 9.0, 1.2).unwrap() < BrownCoal.get_burning_c_at(20.0, 9.0, 1.2).unwrap());
+        assert_eq!(BrownCoal.get_burning_c_at(20.0, 9.0, 0.8), None);
+        assert!((Methane.get_lhv_mj_at(0.0, 0.0) - 50.0).abs() < 0.1);
+        // gasoline holds ~32 GJ/m³
+        let gasoline = Gasoline.get_energy_density_mj_m3_at(0.0, 0.0);
+        assert!((31_000.0..34_000.0).contains(&gasoline), "Gasoline: {}MJ/m³", gasoline);
+        assert_eq!(get_fuels_by_group(GroupFuel::Wood).len(), 4);
+        assert_eq!(get_fuels_by_group(GroupFuel::Coal).len(), 4);
+        assert!(get_fuels_by_group(GroupFuel::Gas).iter().any(|fuel| fuel.get_name(SLang::EN) == "Hydrogen"));
+    }
+    #[test]
+    fn gas() {
+        assert_eq!(Hydrogen.get_density_conditions(), ReferenceConditions::STP);
+        assert_eq!(Granite.get_density_conditions(), ReferenceConditions::ROOM);
+        // ideal gas reproduces the tabulated densities, propane is ~2 % off ideal at STP
+        for gas in [&Hydrogen as &dyn Gas, &Methane, &Propane] {
+            let conditions = gas.get_density_conditions();
+            let density = gas.get_density_kgm3_at_tp(conditions.get_temperature_k(), conditions.pressure);
+            assert!((density / gas.get_density_avg_kgm3() - 1.0).abs() < 0.03, "{}kg/m³", density);
+        }
+        assert!((Hydrogen.get_density_kgm3_at_tp(293.15, 101.325) - 0.0838).abs() < 1e-3);
+        assert!((Hydrogen.get_density_kgm3_at_tp(273.15, 202.65) / Hydrogen.get_density_avg_kgm3() - 2.0).abs() < 0.01);
+        assert!(Gold.as_gas().is_none());
+        assert!(Butane.as_gas().is_some());
+    }
+    #[test]
+    fn gas_mixture() {
+        // air: 28.96 g/mol, 1.293 kg/m³ at STP
+        assert!((Air.get_molar_mass_g_mol() - 28.96).abs() < 0.01);
+        assert!((Air.get_density_avg_kgm3() - 1.293).abs() < 0.003);
+        assert!(Air.as_flammability().is_none());
+        let mass: f32 = Air.get_mass_composition().iter().map(|(_, pct)| pct).sum();
+        assert!((mass - 100.0).abs() < 1e-3);
+        // natural gas: ~5 % lower limit, ~49 MJ/kg
+        assert!((NaturalGas.get_lfl_pct() - 4.96).abs() < 0.05, "{}", NaturalGas.get_lfl_pct());
+        assert!((14.0..16.0).contains(&NaturalGas.get_ufl_pct()));
+        assert!((46.0..49.0).contains(&NaturalGas.get_lhv_avg_mj()), "{}", NaturalGas.get_lhv_avg_mj());
+        assert_eq!(NaturalGas.get_min_ignition_energy_millijoule(), None);
+        // syngas burns over a wide range, mostly thanks to hydrogen and CO
+        assert!(Syngas.is_flammable(50.0));
+        assert!(Syngas.get_lfl_pct() > 5.0);
+        let syngas: &dyn Material = &Syngas;
+        assert!(syngas.as_gas_mixture().is_some_and(|mixture| mixture.get_mole_composition().len() == 5));
+        assert!(FlueGas.as_heat_value().is_none());
+        // nothing combustible - the range is empty, never flammable
+        new_mixture!( Inert,
+            names: [SLang::EN:"Inert"],
+            mole_pct: [[Nitrogen, 90.0], [CarbonDioxide, 10.0]],
+            flammability: [autoignition_c: [0.0, 0.0]],
+        );
+        assert!((0..=20).all(|step| !Inert.is_flammable(step as f32 * 5.0)));
+        assert!(Inert.as_heat_value().is_none());
+        assert_eq!(CarbonDioxide.get_formula(), "CO2");
+        assert!(FlueGas.get_density_avg_kgm3() > Air.get_density_avg_kgm3());
+    }
+    #[test]
+    fn liquid() {
+        // water: 1.0 mPa·s at 20 °C, 0.28 mPa·s at 100 °C
+        assert!((Water.get_viscosity_pa_s(293.15).unwrap() - 1.0e-3).abs() < 0.02e-3);
+        assert!((Water.get_viscosity_pa_s(373.15).unwrap() - 0.282e-3).abs() < 0.01e-3);
+        assert!((Water.get_kinematic_viscosity_m2s(293.15).unwrap() - 1.0e-6).abs() < 0.02e-6);
+        // the Vogel equation breaks down at C = 140 K for water
+        assert_eq!(Water.get_viscosity_pa_s(140.0), None);
+        assert_eq!(Water.get_viscosity_pa_s(100.0), None);
+        assert_eq!(Water.get_kinematic_viscosity_m2s(140.0), None);
+        assert!((Water.get_surface_tension_n_m(293.15) - 0.0728).abs() < 1e-4);
+        assert!((Water.get_boiling_c_at(101.325) - 100.0).abs() < 0.05);
+        assert!((Water.get_vapour_pressure_kpa(293.15) - 2.34).abs() < 0.02);
+        // on Everest water boils at ~71 °C
+        assert!((Water.get_boiling_c_at(33.7) - 71.0).abs() < 1.0);
+        assert!((Ethanol.get_boiling_c_at(101.325) - Ethanol.get_boiling_avg_c()).abs() < 0.5);
+        assert!(EngineOil.get_viscosity_pa_s(293.15).unwrap() > 100.0 * Water.get_viscosity_pa_s(293.15).unwrap());
+        // molten iron is ~5.5 mPa·s at the melting point
+        let iron = MoltenIron.get_viscosity_pa_s(MoltenIron.get_density_conditions().get_temperature_k()).unwrap();
+        assert!((5.0e-3..6.5e-3).contains(&iron), "{}Pa·s", iron);
+        let liquids = ALL_MATERIALS.iter().filter(|material| material.as_liquid().is_some()).count();
+        assert_eq!(liquids, 11);
+        assert!(Gasoline.as_vapour_pressure().is_none());
+    }
+    #[test]
+    fn latent_heat() {
+        assert_eq!(Ice.get_formula(), Steam.get_formula());
+        assert!(Ice.get_density_avg_kgm3() < Water.get_density_avg_kgm3());
+        assert!(Steam.get_density_avg_kgm3() < 1.0);
+        let latent_heat = ALL_MATERIALS.iter().filter_map(|material| material.as_latent_heat());
+        assert!(latent_heat.clone().count() >= 5);
+        // boiling takes more heat than melting
+        assert!(latent_heat
+            .clone()
+            .all(|heat| heat.get_vaporization_kj_kg().or(heat.get_sublimation_kj_kg()).is_some_and(|latent| latent > heat.get_fusion_kj_kg())));
+        // ice turns into vapour by sublimation: melting plus boiling at 0 °C, ~2501 kJ/kg
+        assert_eq!(Ice.get_vaporization_kj_kg(), None);
+        assert_eq!(Water.get_sublimation_kj_kg(), None);
+        let sublimation = Ice.get_sublimation_kj_kg().unwrap();
+        assert!((sublimation - Ice.get_fusion_kj_kg() - 2501.0).abs() < 5.0, "{}kJ/kg", sublimation);
+    }
+    #[test]
+    fn density() {
+        assert!(ALL_MATERIALS.iter().all(|material| material.as_density().is_some_and(|density| density.get_density_avg_kgm3() > 0.0)));
+        // ranges are [min, max], range checks rely on it
+        for material in ALL_MATERIALS {
+            let density = material.as_density().unwrap();
+            assert!(density.get_density_min_gcm3() <= density.get_density_max_gcm3(), "{}", material.get_name(SLang::EN));
+        }
+        assert_eq!((Dural.get_density_min_gcm3(), Dural.get_density_max_gcm3()), (2.77, 2.79));
+    }
+    #[test]
+    fn precious() {
+        let precious: Vec<&dyn Precious> = ALL_MATERIALS.iter().filter_map(|material| material.as_precious()).collect();
+        assert_eq!(precious.len(), 7);
+        assert_eq!(Gold750.get_hallmark().map(|hallmark| hallmark.name), Some("18K"));
+        assert_eq!(SterlingSilver.get_hallmark().map(|hallmark| hallmark.name), Some("Sterling"));
+        // tabulated densities agree with the composition of yellow gold
+        for (alloy, fineness) in [(&Gold750 as &dyn Density, Gold750.get_fineness()), (&Gold585, Gold585.get_fineness())] {
+            let estimate = precious::GoldAlloy {fineness, silver: 0.5}.get_density_kgm3() * 1.0e-3;
+            assert!((alloy.get_density_min_gcm3()..=alloy.get_density_max_gcm3()).contains(&estimate), "{}g/cm³", estimate);
+        }
+    }
+    #[test]
+    fn composite() {
+        // concrete 2.2-2.5 g/cm³, sandstone 2.0-2.6 g/cm³
+        assert!((2.2..2.5).contains(&Concrete.get_density_avg_gcm3()));
+        assert!(Sandstone.get_density_min_gcm3() >= 2.0 && Sandstone.get_density_max_gcm3() <= 2.6);
+        for composite in [&Concrete as &dyn Composite, &Sandstone] {
+            let total: Percent = composite.get_constituents().iter().map(|(_, pct)| pct).sum();
+            assert!((total - 100.0).abs() < 1e-3);
+            // water conducts heat better than air
+            assert!(composite.get_effective_conductivity_wmk(100.0) > composite.get_effective_conductivity_wmk(0.0));
+            assert!(composite.get_dry_density_kgm3() < composite.get_saturated_density_kgm3());
+        }
+        // sandstone 1.5-4 W/(m·K), concrete 1-2.5 W/(m·K)
+        assert!((1.5..4.0).contains(&Sandstone.get_thermal_conductivity_avg_wmk()));
+        assert!((1.0..2.5).contains(&Concrete.get_thermal_conductivity_avg_wmk()));
+        assert!((800.0..1100.0).contains(&Concrete.get_specific_heat_avg_jkgk()));
+    }
+}
@@ -0,0 +1,171 @@
+//! Purity of precious metals: millesimal fineness, karat and percent, hallmark
+//! standards, and density and melting estimates of gold–silver–copper alloys.
+//!
+//! Fineness is the mass share of the precious metal in parts per thousand, 24 karat is
+//! pure gold. Alloy densities assume that the volumes of the metals add up, melting is
+//! an empirical liquidus fit of the Au–Ag–Cu jewellery alloys, good to about ±30 °C.
+//!
+//! Проба драгоценных металлов, каратность, клейма и свойства сплавов золота.
+
+use crate::{Celsius, Copper, Density, Gold, KGm3, Material, Melting, Percent, Platinum, Silver};
+
+/// Lowering of the liquidus of Au–Ag–Cu alloys against the mass-weighted melting points,
+/// multiplied by `w·(1 - w)` of gold, °C
+const LIQUIDUS_DEPRESSION_C: f32 = 715.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreciousMetal {
+    /// Gold.<br>
+    /// Золото.
+    Gold,
+    /// Silver.<br>
+    /// Серебро.
+    Silver,
+    /// Platinum.<br>
+    /// Платина.
+    Platinum,
+}
+
+impl PreciousMetal {
+    /// Pure metal
+    pub fn get_material(&self) -> &'static dyn Material {
+        match self {
+            PreciousMetal::Gold => &Gold,
+            PreciousMetal::Silver => &Silver,
+            PreciousMetal::Platinum => &Platinum,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// Mass share of the precious metal in an alloy
+pub struct Fineness {
+    /// Parts per thousand, 1000 - pure metal
+    pub millesimal: f32,
+}
+
+impl Fineness {
+    pub const PURE: Fineness = Fineness {millesimal: 1000.0};
+    /// 24 karat - pure gold
+    pub fn from_karat(karat: f32) -> Self {
+        Fineness {millesimal: karat / 24.0 * 1000.0}
+    }
+    pub fn from_percent(percent: Percent) -> Self {
+        Fineness {millesimal: percent * 10.0}
+    }
+    pub fn get_karat(&self) -> f32 {
+        self.millesimal * 24.0 / 1000.0
+    }
+    pub fn get_percent(&self) -> Percent {
+        self.millesimal * 0.1
+    }
+    /// Mass share, 0.0..1.0
+    pub fn get_share(&self) -> f32 {
+        self.millesimal / 1000.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Hallmark standard: the lowest fineness an article must have to be stamped with it
+pub struct Hallmark {
+    pub metal: PreciousMetal,
+    pub millesimal: u16,
+    /// Common trade name
+    pub name: &'static str,
+}
+
+/// Common hallmark standards
+pub const HALLMARKS: &[Hallmark] = &[
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 999, name: "24K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 958, name: "23K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 916, name: "22K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 750, name: "18K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 585, name: "14K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 417, name: "10K"},
+    Hallmark {metal: PreciousMetal::Gold, millesimal: 375, name: "9K"},
+    Hallmark {metal: PreciousMetal::Silver, millesimal: 999, name: "Fine"},
+    Hallmark {metal: PreciousMetal::Silver, millesimal: 958, name: "Britannia"},
+    Hallmark {metal: PreciousMetal::Silver, millesimal: 925, name: "Sterling"},
+    Hallmark {metal: PreciousMetal::Silver, millesimal: 900, name: "Coin"},
+    Hallmark {metal: PreciousMetal::Silver, millesimal: 800, name: "800"},
+    Hallmark {metal: PreciousMetal::Platinum, millesimal: 999, name: "999"},
+    Hallmark {metal: PreciousMetal::Platinum, millesimal: 950, name: "950"},
+    Hallmark {metal: PreciousMetal::Platinum, millesimal: 900, name: "900"},
+    Hallmark {metal: PreciousMetal::Platinum, millesimal: 850, name: "850"},
+];
+
+/// Highest hallmark standard of the `metal` met by `fineness`, `None` - below all of them
+pub fn get_hallmark(metal: PreciousMetal, fineness: Fineness) -> Option<&'static Hallmark> {
+    HALLMARKS
+        .iter()
+        .filter(|hallmark| hallmark.metal == metal && hallmark.millesimal as f32 <= fineness.millesimal)
+        .max_by_key(|hallmark| hallmark.millesimal)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Jewellery gold alloyed with silver and copper
+pub struct GoldAlloy {
+    pub fineness: Fineness,
+    /// Share of silver in the ligature, the rest is copper.
+    /// 0.0 - red gold, 0.5 - yellow gold, 1.0 - green gold
+    pub silver: f32,
+}
+
+impl GoldAlloy {
+    /// Mass shares of gold, silver and copper
+    pub fn get_shares(&self) -> [f32; 3] {
+        let gold = self.fineness.get_share().clamp(0.0, 1.0);
+        let silver = (1.0 - gold) * self.silver.clamp(0.0, 1.0);
+        [gold, silver, 1.0 - gold - silver]
+    }
+    /// Density, kg/m³
+    pub fn get_density_kgm3(&self) -> KGm3 {
+        let metals: [&dyn Density; 3] = [&Gold, &Silver, &Copper];
+        let volume: f32 = self.get_shares().iter().zip(metals).map(|(share, metal)| share / metal.get_density_avg_kgm3()).sum();
+        1.0 / volume
+    }
+    /// Liquidus temperature, °C
+    pub fn get_melting_c(&self) -> Celsius {
+        let metals: [&dyn Melting; 3] = [&Gold, &Silver, &Copper];
+        let shares = self.get_shares();
+        let average: Celsius = shares.iter().zip(metals).map(|(share, metal)| share * metal.get_melting_avg_c()).sum();
+        average - LIQUIDUS_DEPRESSION_C * shares[0] * (1.0 - shares[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fineness() {
+        let gold = Fineness::from_karat(18.0);
+        assert_eq!(gold.millesimal, 750.0);
+        assert_eq!(gold.get_percent(), 75.0);
+        assert!((Fineness {millesimal: 585.0}.get_karat() - 14.04).abs() < 1e-4);
+        assert_eq!(Fineness::from_percent(92.5).millesimal, 925.0);
+        assert_eq!(get_hallmark(PreciousMetal::Gold, Fineness::PURE).map(|hallmark| hallmark.name), Some("24K"));
+        // 749 is not 18 karat
+        assert_eq!(get_hallmark(PreciousMetal::Gold, Fineness {millesimal: 749.0}).map(|hallmark| hallmark.millesimal), Some(585));
+        assert_eq!(get_hallmark(PreciousMetal::Silver, Fineness {millesimal: 930.0}).map(|hallmark| hallmark.name), Some("Sterling"));
+        assert_eq!(get_hallmark(PreciousMetal::Platinum, Fineness {millesimal: 500.0}), None);
+        assert_eq!(PreciousMetal::Silver.get_material().get_name(crate::SLang::EN), "Silver");
+    }
+    #[test]
+    fn gold_alloy() {
+        let yellow = GoldAlloy {fineness: Fineness::from_karat(18.0), silver: 0.5};
+        assert_eq!(yellow.get_shares(), [0.75, 0.125, 0.125]);
+        let density = yellow.get_density_kgm3();
+        assert!((15_300.0..15_700.0).contains(&density), "{}kg/m³", density);
+        // red gold holds more copper and is lighter than green gold
+        let red = GoldAlloy {silver: 0.0, ..yellow};
+        let green = GoldAlloy {silver: 1.0, ..yellow};
+        assert!(red.get_density_kgm3() < green.get_density_kgm3());
+        // liquidus of 18K ~ 900-950 °C, 14K ~ 850-900 °C, pure gold 1064 °C
+        assert!((880.0..960.0).contains(&yellow.get_melting_c()), "{}°C", yellow.get_melting_c());
+        let fourteen = GoldAlloy {fineness: Fineness {millesimal: 585.0}, silver: 0.3};
+        assert!((830.0..900.0).contains(&fourteen.get_melting_c()), "{}°C", fourteen.get_melting_c());
+        let pure = GoldAlloy {fineness: Fineness::PURE, silver: 0.5};
+        assert!((pure.get_melting_c() - Gold.get_melting_avg_c()).abs() < 0.1);
+    }
+}
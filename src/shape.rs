@@ -0,0 +1,183 @@
+//! Volume, mass and weight of a body of a given shape made of a material.
+//!
+//! Mass follows the `Density` range of the material, so it comes as min/avg/max.
+//! All sizes are in metres, the cross-section of a `Profile` in mm² as in steel tables.
+//!
+//! Объём, масса и вес тела заданной формы.
+
+use crate::buoyancy::STANDARD_GRAVITY;
+use crate::{CubicMeter, Density, Kilogram, Meter, Mm2, Newton};
+use std::f32::consts::PI;
+
+/// Gravity on Earth, m/s²
+pub const EARTH_GRAVITY: f32 = STANDARD_GRAVITY;
+/// Gravity on the Moon, m/s²
+pub const MOON_GRAVITY: f32 = 1.62;
+/// Gravity on Mars, m/s²
+pub const MARS_GRAVITY: f32 = 3.721;
+/// Gravity on Venus, m/s²
+pub const VENUS_GRAVITY: f32 = 8.87;
+/// Gravity on Jupiter, m/s²
+pub const JUPITER_GRAVITY: f32 = 24.79;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Cross-section of a solid bar
+pub enum RodSection {
+    /// Round bar, size - diameter.<br>
+    /// Круг, размер - диаметр.
+    Round,
+    /// Square bar, size - side.<br>
+    /// Квадрат, размер - сторона.
+    Square,
+    /// Hexagonal bar, size - across flats.<br>
+    /// Шестигранник, размер - под ключ.
+    Hexagon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// Rectangular block.<br>
+    /// Брус.
+    Box {length: Meter, width: Meter, height: Meter},
+    /// Cylinder, e.g. a column.<br>
+    /// Цилиндр.
+    Cylinder {diameter: Meter, length: Meter},
+    /// Sphere.<br>
+    /// Шар.
+    Sphere {diameter: Meter},
+    /// Round tube or pipe.<br>
+    /// Труба.
+    Tube {outer_diameter: Meter, wall: Meter, length: Meter},
+    /// Sheet or plate.<br>
+    /// Лист.
+    Sheet {length: Meter, width: Meter, thickness: Meter},
+    /// Solid bar.<br>
+    /// Пруток.
+    Rod {section: RodSection, size: Meter, length: Meter},
+    /// Any rolled profile (beam, channel, angle) given its cross-section area, mm².<br>
+    /// Профиль по площади сечения.
+    Profile {section: Mm2, length: Meter},
+}
+
+impl Shape {
+    /// Every size is finite and not negative
+    fn is_valid(&self) -> bool {
+        let valid = |sizes: &[f32]| sizes.iter().all(|size| size.is_finite() && *size >= 0.0);
+        match *self {
+            Shape::Box {length, width, height} => valid(&[length, width, height]),
+            Shape::Cylinder {diameter, length} => valid(&[diameter, length]),
+            Shape::Sphere {diameter} => valid(&[diameter]),
+            Shape::Tube {outer_diameter, wall, length} => valid(&[outer_diameter, wall, length]),
+            Shape::Sheet {length, width, thickness} => valid(&[length, width, thickness]),
+            Shape::Rod {size, length, ..} => valid(&[size, length]),
+            Shape::Profile {section, length} => valid(&[section, length]),
+        }
+    }
+    /// Volume, m³. <br>
+    /// `None` if any size is negative or not finite.
+    pub fn get_volume_m3(&self) -> Option<CubicMeter> {
+        if !self.is_valid() {
+            return None;
+        }
+        let volume = match *self {
+            Shape::Box {length, width, height} => length * width * height,
+            Shape::Cylinder {diameter, length} => PI * diameter * diameter / 4.0 * length,
+            Shape::Sphere {diameter} => PI * diameter.powi(3) / 6.0,
+            Shape::Tube {outer_diameter, wall, length} => {
+                let inner = (outer_diameter - 2.0 * wall).max(0.0);
+                PI * (outer_diameter * outer_diameter - inner * inner) / 4.0 * length
+            }
+            Shape::Sheet {length, width, thickness} => length * width * thickness,
+            Shape::Rod {section, size, length} => {
+                let area = match section {
+                    RodSection::Round => PI * size * size / 4.0,
+                    RodSection::Square => size * size,
+                    RodSection::Hexagon => 3.0_f32.sqrt() / 2.0 * size * size,
+                };
+                area * length
+            }
+            Shape::Profile {section, length} => section * 1.0e-6 * length,
+        };
+        Some(volume)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Value spread by the density range of the material
+pub struct MinAvgMax {
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeMass {
+    pub volume: CubicMeter,
+    /// Mass, kg
+    pub mass: MinAvgMax,
+}
+
+impl ShapeMass {
+    /// Weight under `gravity`, m/s², N. See `EARTH_GRAVITY` and others
+    pub fn get_weight_n(&self, gravity: f32) -> MinAvgMax {
+        let weight = |mass: Kilogram| -> Newton {mass * gravity};
+        MinAvgMax {min: weight(self.mass.min), avg: weight(self.mass.avg), max: weight(self.mass.max)}
+    }
+}
+
+/// Volume and mass of `shape` made of `material`. <br>
+/// `None` if any size of the shape is negative or not finite.
+pub fn get_mass(material: &dyn Density, shape: &Shape) -> Option<ShapeMass> {
+    let volume = shape.get_volume_m3()?;
+    let mass = MinAvgMax {
+        min: material.get_density_min_gcm3() * 1000.0 * volume,
+        avg: material.get_density_avg_kgm3() * volume,
+        max: material.get_density_max_gcm3() * 1000.0 * volume,
+    };
+    Some(ShapeMass {volume, mass})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Copper, Iron};
+
+    #[test]
+    fn volume() {
+        let cube = Shape::Box {length: 1.0, width: 2.0, height: 3.0};
+        assert_eq!(cube.get_volume_m3(), Some(6.0));
+        assert!((Shape::Sphere {diameter: 2.0}.get_volume_m3().unwrap() - 4.0 / 3.0 * PI).abs() < 1e-5);
+        // a tube is a cylinder minus its bore
+        let tube = Shape::Tube {outer_diameter: 0.1, wall: 0.01, length: 2.0}.get_volume_m3().unwrap();
+        let outer = Shape::Cylinder {diameter: 0.1, length: 2.0}.get_volume_m3().unwrap();
+        let bore = Shape::Cylinder {diameter: 0.08, length: 2.0}.get_volume_m3().unwrap();
+        assert!((tube - (outer - bore)).abs() < 1e-7);
+        let round = Shape::Rod {section: RodSection::Round, size: 0.02, length: 1.0};
+        assert!((round.get_volume_m3().unwrap() - Shape::Cylinder {diameter: 0.02, length: 1.0}.get_volume_m3().unwrap()).abs() < 1e-9);
+        let hexagon = Shape::Rod {section: RodSection::Hexagon, size: 0.02, length: 1.0}.get_volume_m3().unwrap();
+        let square = Shape::Rod {section: RodSection::Square, size: 0.02, length: 1.0}.get_volume_m3().unwrap();
+        assert!(round.get_volume_m3().unwrap() < hexagon && hexagon < square);
+        // IPE 200 beam, 2850 mm²
+        assert!((Shape::Profile {section: 2850.0, length: 6.0}.get_volume_m3().unwrap() - 0.0171).abs() < 1e-6);
+    }
+    #[test]
+    fn mass_and_weight() {
+        // 1 mm copper sheet of 1 m² weighs ~8.9 kg
+        let sheet = get_mass(&Copper, &Shape::Sheet {length: 1.0, width: 1.0, thickness: 0.001}).unwrap();
+        assert!((sheet.mass.avg - 8.92).abs() < 0.01);
+        let rail = get_mass(&Iron, &Shape::Profile {section: 7_650.0, length: 1.0}).unwrap();
+        assert!(rail.mass.min <= rail.mass.avg && rail.mass.avg <= rail.mass.max);
+        let earth = rail.get_weight_n(EARTH_GRAVITY);
+        let moon = rail.get_weight_n(MOON_GRAVITY);
+        assert!((earth.avg / moon.avg - EARTH_GRAVITY / MOON_GRAVITY).abs() < 1e-3);
+    }
+    #[test]
+    fn invalid_sizes() {
+        assert_eq!(Shape::Box {length: 1.0, width: -2.0, height: 3.0}.get_volume_m3(), None);
+        assert_eq!(Shape::Sphere {diameter: f32::NAN}.get_volume_m3(), None);
+        assert_eq!(Shape::Profile {section: 2850.0, length: f32::INFINITY}.get_volume_m3(), None);
+        assert!(get_mass(&Copper, &Shape::Cylinder {diameter: -0.1, length: 1.0}).is_none());
+        // a zero size is a valid empty body
+        assert_eq!(Shape::Sheet {length: 1.0, width: 1.0, thickness: 0.0}.get_volume_m3(), Some(0.0));
+    }
+}
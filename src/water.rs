@@ -0,0 +1,208 @@
+//! Phases of water: ice, liquid water and steam.
+//!
+//! Liquid density follows Kell (1975) with its maximum near 4 °C, saturation pressure
+//! follows Wagner–Pruss over liquid water and Wagner–Riethmann–Feistel–Harvey over ice,
+//! steam density comes from the Peng–Robinson equation of state of `Steam`.
+//! Heat of vaporization away from the boiling point is scaled by the Watson correlation.
+//! Enthalpy at atmospheric pressure lets freezing, thawing and boiling be simulated
+//! by adding or removing heat.
+//!
+//! Фазы воды: лёд, вода и пар.
+
+use crate::{Boiling, Celsius, Density, Gas, Ice, KGm3, KJ_kg, KPa, Kelvin, LatentHeat, Melting, Phase, Steam, Thermal, Water};
+
+/// Triple point of water, K
+pub const TRIPLE_POINT_K: Kelvin = 273.16;
+/// Pressure at the triple point of water, kPa
+pub const TRIPLE_POINT_KPA: KPa = 0.611_657;
+/// Temperature of the maximum density of liquid water at 101.325 kPa, °C
+pub const MAX_DENSITY_C: Celsius = 3.98;
+/// Isothermal compressibility of liquid water, 1/kPa
+const COMPRESSIBILITY: f64 = 4.6e-7;
+/// Exponent of the Watson correlation
+const WATSON_EXPONENT: f32 = 0.38;
+
+/// Mass shares of the phases, they add up to 1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaterState {
+    pub temperature: Celsius,
+    pub ice: f32,
+    pub liquid: f32,
+    pub steam: f32,
+}
+
+impl WaterState {
+    /// Phase holding most of the mass
+    pub fn get_phase(&self) -> Phase {
+        if self.ice >= self.liquid && self.ice >= self.steam {
+            Phase::Solid
+        } else if self.liquid >= self.steam {
+            Phase::Liquid
+        } else {
+            Phase::Gas
+        }
+    }
+}
+
+/// Density of liquid water at `t`, `p`, kg/m³ (Kell, 0..150 °C, supercooled water down to -30 °C)
+pub fn get_liquid_density_kgm3(t: Celsius, p: KPa) -> KGm3 {
+    let t = t as f64;
+    let density = (999.839_52 + 16.945_176 * t - 7.987_040_1e-3 * t.powi(2) - 46.170_461e-6 * t.powi(3)
+        + 105.563_02e-9 * t.powi(4)
+        - 280.542_53e-12 * t.powi(5))
+        / (1.0 + 16.879_850e-3 * t);
+    (density * (1.0 + COMPRESSIBILITY * (p as f64 - 101.325))) as f32
+}
+
+/// Saturation pressure of the vapour over water, or over ice below the triple point, kPa
+pub fn get_saturation_pressure_kpa(t: Kelvin) -> KPa {
+    let t = t as f64;
+    if t < TRIPLE_POINT_K as f64 {
+        let theta = t / TRIPLE_POINT_K as f64;
+        let ln = -13.928_169 * (1.0 - theta.powf(-1.5)) + 34.707_823 * (1.0 - theta.powf(-1.25));
+        return (TRIPLE_POINT_KPA as f64 * ln.exp()) as f32;
+    }
+    let (tc, pc) = (Steam.get_critical_temperature_k() as f64, Steam.get_critical_pressure_kpa() as f64);
+    let tau = 1.0 - (t / tc).min(1.0);
+    let ln = tc / t
+        * (-7.859_517_83 * tau + 1.844_082_59 * tau.powf(1.5) - 11.786_649_7 * tau.powi(3) + 22.680_741_1 * tau.powf(3.5)
+            - 15.961_871_9 * tau.powi(4)
+            + 1.801_225_02 * tau.powf(7.5));
+    (pc * ln.exp()) as f32
+}
+
+/// Temperature at which water boils (ice sublimates) at `p`, K
+pub fn get_saturation_temperature_k(p: KPa) -> Kelvin {
+    // saturation pressure grows with temperature, bisect up to the critical point
+    let (mut low, mut high) = (150.0_f32, Steam.get_critical_temperature_k());
+    for _ in 0..60 {
+        let middle = (low + high) * 0.5;
+        if get_saturation_pressure_kpa(middle) < p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) * 0.5
+}
+
+/// Phase of water at `t`, `p`. Melting point is taken independent of pressure
+pub fn get_phase(t: Kelvin, p: KPa) -> Phase {
+    if t >= Steam.get_critical_temperature_k() || p < get_saturation_pressure_kpa(t) {
+        Phase::Gas
+    } else if t < Ice.get_melting_avg_k() {
+        Phase::Solid
+    } else {
+        Phase::Liquid
+    }
+}
+
+/// Density of water in the phase stable at `t`, `p`, kg/m³
+pub fn get_density_kgm3(t: Kelvin, p: KPa) -> KGm3 {
+    match get_phase(t, p) {
+        Phase::Solid => Ice.get_density_avg_kgm3(),
+        Phase::Liquid => get_liquid_density_kgm3(t - 273.15, p),
+        Phase::Gas => Steam.get_real_density_kgm3_at_tp(t, p),
+    }
+}
+
+/// Heat of vaporization at the saturation temperature `t`, kJ/kg (Watson). 0.0 above the critical point
+pub fn get_vaporization_kj_kg(t: Kelvin) -> KJ_kg {
+    let tc = Steam.get_critical_temperature_k();
+    let ratio = (tc - t).max(0.0) / (tc - Water.get_boiling_avg_k());
+    get_latent_heats().1 * ratio.powf(WATSON_EXPONENT)
+}
+
+/// Heats of fusion and vaporization of water at 101.325 kPa, kJ/kg
+fn get_latent_heats() -> (KJ_kg, KJ_kg) {
+    (Water.get_fusion_kj_kg(), Water.get_vaporization_kj_kg().unwrap_or_default())
+}
+
+/// Specific heats of ice, water and steam, kJ/(kg·K)
+fn get_specific_heats() -> (f32, f32, f32) {
+    let specific_heat = |material: &dyn Thermal| material.get_specific_heat_avg_jkgk() * 1.0e-3;
+    (specific_heat(&Ice), specific_heat(&Water), specific_heat(&Steam))
+}
+
+/// Specific enthalpy at 101.325 kPa, kJ/kg, zero for ice at the melting point
+pub fn get_enthalpy_kj_kg(state: &WaterState) -> KJ_kg {
+    let (c_ice, c_water, c_steam) = get_specific_heats();
+    let (melting, boiling) = (Water.get_melting_avg_c(), Water.get_boiling_avg_c());
+    let (fusion, vaporization) = get_latent_heats();
+    let t = state.temperature;
+    let sensible = |c: f32, from: Celsius| c * (t - from);
+    state.ice * sensible(c_ice, melting)
+        + state.liquid * (fusion + sensible(c_water, melting))
+        + state.steam * (fusion + c_water * (boiling - melting) + vaporization + sensible(c_steam, boiling))
+}
+
+/// Temperature and phase shares of water with specific enthalpy `h` at 101.325 kPa, see `get_enthalpy_kj_kg`
+pub fn get_state_from_enthalpy(h: KJ_kg) -> WaterState {
+    let (c_ice, c_water, c_steam) = get_specific_heats();
+    let (melting, boiling) = (Water.get_melting_avg_c(), Water.get_boiling_avg_c());
+    let (fusion, vaporization) = get_latent_heats();
+    let heated = fusion + c_water * (boiling - melting);
+    let state = |temperature, ice, liquid, steam| WaterState {temperature, ice, liquid, steam};
+    if h < 0.0 {
+        state(melting + h / c_ice, 1.0, 0.0, 0.0)
+    } else if h < fusion {
+        state(melting, 1.0 - h / fusion, h / fusion, 0.0)
+    } else if h < heated {
+        state(melting + (h - fusion) / c_water, 0.0, 1.0, 0.0)
+    } else if h < heated + vaporization {
+        let steam = (h - heated) / vaporization;
+        state(boiling, 0.0, 1.0 - steam, steam)
+    } else {
+        state(boiling + (h - heated - vaporization) / c_steam, 0.0, 0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn density_anomaly() {
+        let density = |t: Celsius| get_liquid_density_kgm3(t, 101.325);
+        assert!((density(MAX_DENSITY_C) - 999.975).abs() < 0.01);
+        assert!(density(MAX_DENSITY_C) > density(0.0) && density(MAX_DENSITY_C) > density(8.0));
+        assert!((density(100.0) - 958.4).abs() < 0.2);
+        // ice floats
+        assert!(get_density_kgm3(263.15, 101.325) < get_density_kgm3(278.15, 101.325));
+        let steam = get_density_kgm3(373.15 + 10.0, 101.325);
+        assert!((0.55..0.6).contains(&steam), "{}kg/m³", steam);
+    }
+    #[test]
+    fn saturation() {
+        let boiling = get_saturation_pressure_kpa(373.15);
+        assert!((boiling - 101.325).abs() < 0.1, "{}kPa", boiling);
+        assert!((get_saturation_pressure_kpa(263.15) - 0.260).abs() < 0.002);
+        assert!((get_saturation_pressure_kpa(TRIPLE_POINT_K) - TRIPLE_POINT_KPA).abs() < 1e-4);
+        // water boils at ~70 °C on top of Everest
+        assert!((get_saturation_temperature_k(33.7) - 273.15 - 71.0).abs() < 1.0);
+        assert_eq!(get_phase(263.15, 101.325), Phase::Solid);
+        assert_eq!(get_phase(293.15, 101.325), Phase::Liquid);
+        assert_eq!(get_phase(393.15, 101.325), Phase::Gas);
+        assert_eq!(get_phase(393.15, 500.0), Phase::Liquid);
+        // ice sublimates in vacuum
+        assert_eq!(get_phase(263.15, 0.1), Phase::Gas);
+        // 2257 kJ/kg at 100 °C, 2454 kJ/kg at 20 °C
+        assert!((get_vaporization_kj_kg(293.15) / 2454.0 - 1.0).abs() < 0.02);
+        assert_eq!(get_vaporization_kj_kg(700.0), 0.0);
+    }
+    #[test]
+    fn enthalpy() {
+        let half_melted = get_state_from_enthalpy(Water.get_fusion_kj_kg() * 0.5);
+        assert_eq!(half_melted.temperature, 0.0);
+        assert!((half_melted.liquid - 0.5).abs() < 1e-6);
+        for h in [-100.0, 100.0, 500.0, 1500.0, 3000.0, 3200.0] {
+            let state = get_state_from_enthalpy(h);
+            assert!((state.ice + state.liquid + state.steam - 1.0).abs() < 1e-6);
+            assert!((get_enthalpy_kj_kg(&state) - h).abs() < 0.01, "{}kJ/kg", h);
+        }
+        assert_eq!(get_state_from_enthalpy(-100.0).get_phase(), Phase::Solid);
+        let warm = get_state_from_enthalpy(Water.get_fusion_kj_kg() + 4.182 * 20.0);
+        assert!((warm.temperature - 20.0).abs() < 0.01);
+        assert_eq!(get_state_from_enthalpy(3000.0).get_phase(), Phase::Gas);
+    }
+}